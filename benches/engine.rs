@@ -0,0 +1,53 @@
+// Per-tick throughput of the engine on a late-tier preset.
+//
+//   cargo bench --bench engine
+//
+// "cached" is a normal run, "rebuild" forces the modifiers in IntermediateState
+// to be rebuilt every tick, which is what every tick cost before they were cached.
+
+use one_life::clock::ManualClock;
+use one_life::engine::engine_run;
+use one_life::game::Game;
+use one_life::presets::get_presets;
+use std::time::{Duration, Instant};
+
+const PRESET: &str = "05: T5 expected";
+const LIVES: u32 = 3;
+
+fn run_life(rebuild: bool) -> (u32, Duration) {
+    let mut game = Game::with_clock(ManualClock::new(0.0));
+    game.load_game(get_presets().remove(PRESET).unwrap());
+    let start = Instant::now();
+    while !game.state.life_stats.is_dying {
+        if rebuild {
+            game.intermediate_state.invalidate();
+        }
+        engine_run(&mut game);
+    }
+    (game.state.life_stats.current_tick, start.elapsed())
+}
+
+fn bench(name: &str, rebuild: bool) {
+    let mut ticks = 0;
+    let mut elapsed = Duration::ZERO;
+    for _ in 0..LIVES {
+        let (life_ticks, life_elapsed) = run_life(rebuild);
+        ticks += life_ticks;
+        elapsed += life_elapsed;
+    }
+    let per_tick = elapsed.as_secs_f64() / ticks as f64;
+    println!(
+        "{:<8} {:>8} ticks in {:>7.3}s, {:>7.2}us/tick, {:>9.0} ticks/s",
+        name,
+        ticks,
+        elapsed.as_secs_f64(),
+        per_tick * 1e6,
+        1.0 / per_tick
+    );
+}
+
+fn main() {
+    println!("{} x{}", PRESET, LIVES);
+    bench("cached", false);
+    bench("rebuild", true);
+}
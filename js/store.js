@@ -15,6 +15,7 @@ export const createStore = function (wasm) {
       previous_recorded_inputs: wasm.get_previous_recorded_inputs(),
       item_queue: wasm.get_world_item_queue(),
       meta: wasm.get_meta_data(),
+      events: [],
       tutorial_data: Object.freeze(tutorial_data),
     },
     mutations: {
@@ -26,9 +27,13 @@ export const createStore = function (wasm) {
       },
 
       update_dynamic_data(state) {
-        recurse_update(state.state, state.wasm.get_state())
+        apply_state_delta(state.state, state.wasm.get_state_delta())
         recurse_update(state.input, state.wasm.get_input())
         recurse_update(state.meta, state.wasm.get_meta_data())
+        let events = state.wasm.drain_events()
+        if (events.length > 0) {
+          state.events = state.events.concat(events).slice(-max_logged_events)
+        }
         if (state.meta.options.show_recorded) {
           let recorded = state.wasm.get_recorded_inputs()
           if (state.recorded_inputs.length != recorded.length) {
@@ -62,6 +67,42 @@ export const createStore = function (wasm) {
   })
 }
 
+const max_logged_events = 100
+
+const delta_values = ['life_stats', 'items', 'rebirth_stats', 'rng']
+const delta_arrays = [
+  'stats',
+  'works',
+  'activities',
+  'housing',
+  'tombs',
+  'blessings',
+  'skills',
+  'boost_items',
+  'life_events',
+]
+
+function apply_state_delta(state, delta) {
+  if (delta.full) {
+    recurse_update(state, delta.full)
+    return
+  }
+  for (const key of delta_values) {
+    if (delta[key]) {
+      recurse_update(state[key], delta[key])
+    }
+  }
+  for (const key of delta_arrays) {
+    for (const entry of delta[key]) {
+      recurse_update(state[key][entry.index], entry.value)
+    }
+  }
+}
+
+function is_object(value) {
+  return value !== null && typeof value == 'object'
+}
+
 function recurse_update(o, o2) {
   for (var key in o2) {
     if (Array.isArray(o2[key])) {
@@ -71,7 +112,8 @@ function recurse_update(o, o2) {
         continue
       }
     }
-    if (typeof o2[key] == 'object') {
+    /* null is an object too, but has to replace the old value like any primitive */
+    if (is_object(o[key]) && is_object(o2[key])) {
       recurse_update(o[key], o2[key])
       continue
     }
@@ -0,0 +1,166 @@
+// Headless simulator that plays a save through several lives without a browser.
+//
+// Examples:
+//   cargo run --release --bin simulator -- --list
+//   cargo run --release --bin simulator -- --preset "05: T5 expected" --lives 5
+//   cargo run --release --bin simulator -- --save-file my_save.txt --target-tier 6
+
+use anyhow::{anyhow, bail, Context, Result};
+use one_life::clock::ManualClock;
+use one_life::game::{Game, GameSave};
+use one_life::input::work::{WorkCategoryTypes, WorkTypes};
+use one_life::presets::get_presets;
+use one_life::util::run_until_dead;
+use one_life::wasm_api::meta::decode_save;
+use one_life::{buy_tier_internal, can_buy_tier_internal};
+use one_life::{do_rebirth_internal, do_rebirth_replay_internal};
+use std::env;
+use std::fs;
+use strum::IntoEnumIterator;
+
+const USAGE: &str = "Usage: simulator [--list] \
+[--preset NAME | --save EXPORTED_SAVE | --save-file PATH] \
+[--lives N] [--target-tier N] [--replay]";
+
+struct Args {
+    list: bool,
+    preset: Option<String>,
+    save: Option<String>,
+    lives: u32,
+    target_tier: Option<u32>,
+    replay: bool,
+}
+
+fn parse_args() -> Result<Args> {
+    let mut args = Args {
+        list: false,
+        preset: None,
+        save: None,
+        lives: 1,
+        target_tier: None,
+        replay: false,
+    };
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| anyhow!("{} needs a value", arg));
+        match arg.as_str() {
+            "--list" => args.list = true,
+            "--replay" => args.replay = true,
+            "--preset" => args.preset = Some(value()?),
+            "--save" => args.save = Some(value()?),
+            "--save-file" => {
+                let path = value()?;
+                let save =
+                    fs::read_to_string(&path).with_context(|| format!("reading {}", path))?;
+                args.save = Some(save);
+            }
+            "--lives" => args.lives = value()?.parse().context("--lives")?,
+            "--target-tier" => args.target_tier = Some(value()?.parse().context("--target-tier")?),
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => bail!("Unknown argument {}\n{}", arg, USAGE),
+        }
+    }
+    Ok(args)
+}
+
+fn load_save(args: &Args) -> Result<GameSave> {
+    if let Some(save) = &args.save {
+        return decode_save(save).context("decoding exported save");
+    }
+    match &args.preset {
+        Some(name) => get_presets()
+            .remove(name.as_str())
+            .ok_or_else(|| anyhow!("No preset named {:?}, see --list", name)),
+        None => Ok(GameSave::default()),
+    }
+}
+
+// The highest job of each category that was worked during the life
+fn top_jobs(game: &Game) -> String {
+    let mut jobs = vec![];
+    for category in WorkCategoryTypes::iter() {
+        let best = WorkTypes::iter()
+            .filter(|work| game.world.get_work(*work).work_type == category)
+            .filter(|work| game.state.works[*work as usize].level > 0)
+            .last();
+        if let Some(work) = best {
+            jobs.push(format!(
+                "{} {}",
+                game.world.get_work(work).display_name,
+                game.state.works[work as usize].level
+            ));
+        }
+    }
+    jobs.join(", ")
+}
+
+fn buy_tiers(game: &mut Game, target_tier: u32) {
+    while game.state.rebirth_stats.tier < target_tier {
+        let next_tier = game.state.rebirth_stats.tier + 1;
+        if !can_buy_tier_internal(next_tier, game) {
+            return;
+        }
+        buy_tier_internal(next_tier, game);
+    }
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    if args.list {
+        for name in get_presets().keys() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    // Life events are seeded from the clock at rebirth, a fixed clock keeps runs
+    // reproducible
+    let mut game = Game::with_clock(ManualClock::new(0.0));
+    game.load_game(load_save(&args)?);
+
+    println!(
+        "{:>4} {:>4} {:>8} {:>6} {:>12} {:>12} {:>14}  jobs",
+        "life", "tier", "ticks", "age", "coins_gain", "coins", "total_ticks"
+    );
+    let mut total_ticks: u64 = 0;
+    for life in 1..=args.lives {
+        let coins_before = game.state.rebirth_stats.coins;
+        let tier = game.state.rebirth_stats.tier;
+        run_until_dead(&mut game);
+        let ticks = game.state.life_stats.current_tick;
+        total_ticks += ticks as u64;
+        println!(
+            "{:>4} {:>4} {:>8} {:>6.1} {:>12.1} {:>12.1} {:>14}  {}",
+            life,
+            tier,
+            ticks,
+            game.state.life_stats.age / 365.0,
+            game.state.rebirth_stats.coins - coins_before,
+            game.state.rebirth_stats.coins,
+            total_ticks,
+            top_jobs(&game)
+        );
+
+        if let Some(target_tier) = args.target_tier {
+            buy_tiers(&mut game, target_tier);
+            if game.state.rebirth_stats.tier >= target_tier {
+                println!(
+                    "Reached tier {} after {} lives and {} ticks",
+                    game.state.rebirth_stats.tier, life, total_ticks
+                );
+                break;
+            }
+        }
+        if life < args.lives {
+            if args.replay {
+                do_rebirth_replay_internal(&mut game);
+            } else {
+                do_rebirth_internal(&mut game);
+            }
+        }
+    }
+    Ok(())
+}
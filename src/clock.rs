@@ -0,0 +1,55 @@
+use std::sync::{Arc, Mutex};
+
+// Source of wall clock time in milliseconds, used for autosaves and missed ticks
+pub trait Clock: Send {
+    fn now(&self) -> f64;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[cfg(target_arch = "wasm32")]
+    fn now(&self) -> f64 {
+        js_sys::Date::new_0().get_time()
+    }
+
+    // Native builds (the headless simulator and tests) can't reach the browser clock
+    #[cfg(not(target_arch = "wasm32"))]
+    fn now(&self) -> f64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs_f64() * 1000.0)
+            .unwrap_or(0.0)
+    }
+}
+
+// A clock that only moves when told to. Clones share the same time, so a test
+// can keep one handle while the game owns the other.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    time: Arc<Mutex<f64>>,
+}
+
+impl ManualClock {
+    pub fn new(start: f64) -> ManualClock {
+        ManualClock {
+            time: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    pub fn advance(&self, ms: f64) {
+        *self.time.lock().unwrap() += ms;
+    }
+
+    pub fn set(&self, ms: f64) {
+        *self.time.lock().unwrap() = ms;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> f64 {
+        *self.time.lock().unwrap()
+    }
+}
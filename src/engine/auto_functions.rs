@@ -1,7 +1,16 @@
 use crate::{
-    game::Game, input::options::AutoSettingTypes, world_content::boost_item::translate_boost_item,
-    world_content::tomb::translate_tomb, WORLD,
+    events::GameEventType,
+    game::Game,
+    input::blessing::BlessingTypes,
+    input::boost_item::BoostItemTypes,
+    input::options::AutoSettingTypes,
+    input::tomb::TombTypes,
+    world_content::boost_item::translate_boost_item,
+    world_content::challenge::{challenge_allows_boost_items, challenge_allows_housing},
+    world_content::tomb::translate_tomb,
+    WORLD,
 };
+use strum::IntoEnumIterator;
 
 pub fn auto_work(game: &mut Game) {
     let current_work = WORLD.get_work(game.input.work);
@@ -16,38 +25,61 @@ pub fn auto_work(game: &mut Game) {
 
 pub fn auto_living(game: &mut Game) {
     let current_housing = WORLD.get_housing(game.input.housing);
+    // Don't move in on a windfall and get evicted once it's over
+    let income = game
+        .intermediate_state
+        .get_lasting_value(game.input.work.into());
     for housing in game.state.housing.iter() {
         let housing_world = WORLD.get_housing(housing.name);
-        let can_afford = housing_world.upkeep < game.state.items.income;
+        let can_afford = housing_world.upkeep < income;
         let better_housing = housing.name > current_housing.name;
-        if better_housing && housing.is_unlocked && can_afford {
+        let allowed = challenge_allows_housing(housing.name, game);
+        if better_housing && housing.is_unlocked && can_afford && allowed {
             game.input.housing = housing.name;
         }
     }
 }
 
 pub fn auto_buy_item(game: &mut Game) {
-    for item in game.state.boost_items.iter_mut() {
-        let world_item = translate_boost_item(item.name);
+    if !challenge_allows_boost_items(game) {
+        return;
+    }
+    for item_type in BoostItemTypes::iter() {
+        let world_item = translate_boost_item(item_type);
+        let item = &mut game.state.boost_items[item_type as usize];
         let can_afford = game.state.items.money >= world_item.purchasing_cost;
         if !item.is_purchased && item.is_unlocked && item.is_visible && can_afford {
             item.is_purchased = true;
             game.state.items.money -= world_item.purchasing_cost;
+            game.push_event(GameEventType::ItemBought {
+                item: item_type,
+                auto: true,
+            });
         }
     }
 }
 
 pub fn auto_buy_blessing(game: &mut Game) {
-    for blessing in game.state.blessings.iter_mut() {
+    for blessing_type in BlessingTypes::iter() {
+        let blessing = &mut game.state.blessings[blessing_type as usize];
         let can_afford = game.state.items.divine_favor >= blessing.next_level_cost;
         if blessing.is_unlocked && blessing.is_visible && can_afford {
             blessing.level += 1;
             game.state.items.divine_favor -= blessing.next_level_cost;
+            let level = blessing.level;
+            game.push_event(GameEventType::BlessingBought {
+                blessing: blessing_type,
+                level,
+                auto: true,
+            });
         }
     }
 }
 
 pub fn auto_buy_queued_item(game: &mut Game) {
+    if !challenge_allows_boost_items(game) {
+        return;
+    }
     for item_type in game.input.item_queue.clone().into_iter() {
         let item_world = translate_boost_item(item_type);
         let item = &mut game.state.boost_items[item_type as usize];
@@ -55,6 +87,10 @@ pub fn auto_buy_queued_item(game: &mut Game) {
         if !item.is_purchased && item.is_unlocked && item.is_visible && can_afford {
             item.is_purchased = true;
             game.state.items.money -= item_world.purchasing_cost;
+            game.push_event(GameEventType::ItemBought {
+                item: item_type,
+                auto: true,
+            });
         } else {
             break;
         }
@@ -65,12 +101,17 @@ pub fn auto_buy_queued_item(game: &mut Game) {
 }
 
 pub fn auto_buy_tomb(game: &mut Game) {
-    for tomb in game.state.tombs.iter_mut() {
-        let world_tomb = translate_tomb(tomb.name);
+    for tomb_type in TombTypes::iter() {
+        let world_tomb = translate_tomb(tomb_type);
+        let tomb = &mut game.state.tombs[tomb_type as usize];
         let can_afford = game.state.items.money >= world_tomb.purchasing_cost;
         if !tomb.is_purchased && tomb.is_unlocked && tomb.is_visible && can_afford {
             tomb.is_purchased = true;
             game.state.items.money -= world_tomb.purchasing_cost;
+            game.push_event(GameEventType::TombBought {
+                tomb: tomb_type,
+                auto: true,
+            });
         }
     }
 }
@@ -0,0 +1,166 @@
+use crate::input::achievement::AchievementTypes;
+use crate::input::activity::ActivityTypes;
+use crate::input::blessing::BlessingTypes;
+use crate::input::boost_item::BoostItemTypes;
+use crate::input::challenge::ChallengeTypes;
+use crate::input::deity::DeityTypes;
+use crate::input::housing::HousingTypes;
+use crate::input::karma_upgrade::KarmaUpgradeTypes;
+use crate::input::life_event::LifeEventTypes;
+use crate::input::rebirth_upgrade::RebirthUpgradeTypes;
+use crate::input::skill::SkillTypes;
+use crate::input::stat::StatTypes;
+use crate::input::tomb::TombTypes;
+use crate::input::work::WorkTypes;
+use crate::WORLD;
+use serde::Serialize;
+
+// The entity a base or multiplier in IntermediateState comes from
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type", content = "id")]
+pub enum GainSource {
+    // Not tied to an entity, e.g. the time factor kept in RebirthStats
+    Base,
+    Achievement(AchievementTypes),
+    Activity(ActivityTypes),
+    Blessing(BlessingTypes),
+    BoostItem(BoostItemTypes),
+    Challenge(ChallengeTypes),
+    // The share of the money on hand a merchant work earns
+    Commission(WorkTypes),
+    Deity(DeityTypes),
+    Housing(HousingTypes),
+    KarmaUpgrade(KarmaUpgradeTypes),
+    LifeEvent(LifeEventTypes),
+    RebirthUpgrade(RebirthUpgradeTypes),
+    Skill(SkillTypes),
+    Stat(StatTypes),
+    Tomb(TombTypes),
+    Work(WorkTypes),
+    // The bonus a work gets from its own level
+    WorkLevel(WorkTypes),
+}
+
+impl GainSource {
+    pub fn display_name(&self) -> String {
+        match *self {
+            GainSource::Base => "Base".to_string(),
+            GainSource::Achievement(achievement) => WORLD.achievements[achievement as usize]
+                .display_name
+                .to_string(),
+            GainSource::Activity(activity) => WORLD.get_activity(activity).display_name.to_string(),
+            GainSource::Blessing(blessing) => {
+                WORLD.blessings[blessing as usize].display_name.to_string()
+            }
+            GainSource::BoostItem(item) => {
+                WORLD.boost_items[item as usize].display_name.to_string()
+            }
+            GainSource::Challenge(challenge) => WORLD.challenges[challenge as usize]
+                .display_name
+                .to_string(),
+            GainSource::Commission(work) => {
+                format!("{} commission", WORLD.get_work(work).display_name)
+            }
+            GainSource::Deity(deity) => WORLD.deities[deity as usize].display_name.to_string(),
+            GainSource::Housing(housing) => WORLD.get_housing(housing).display_name.to_string(),
+            GainSource::KarmaUpgrade(upgrade) => WORLD.karma_upgrades[upgrade as usize]
+                .display_name
+                .to_string(),
+            GainSource::LifeEvent(life_event) => WORLD.life_events[life_event as usize]
+                .display_name
+                .to_string(),
+            GainSource::RebirthUpgrade(upgrade) => {
+                WORLD.get_rebirth_upgrade(upgrade).display_name.to_string()
+            }
+            GainSource::Skill(skill) => WORLD.skills[skill as usize].display_name.to_string(),
+            GainSource::Stat(stat) => WORLD.stats[stat as usize].display_name.to_string(),
+            GainSource::Tomb(tomb) => WORLD.tombs[tomb as usize].display_name.to_string(),
+            GainSource::Work(work) => WORLD.get_work(work).display_name.to_string(),
+            GainSource::WorkLevel(work) => format!("{} level", WORLD.get_work(work).display_name),
+        }
+    }
+}
+
+impl From<AchievementTypes> for GainSource {
+    fn from(achievement: AchievementTypes) -> Self {
+        GainSource::Achievement(achievement)
+    }
+}
+
+impl From<ActivityTypes> for GainSource {
+    fn from(activity: ActivityTypes) -> Self {
+        GainSource::Activity(activity)
+    }
+}
+
+impl From<BlessingTypes> for GainSource {
+    fn from(blessing: BlessingTypes) -> Self {
+        GainSource::Blessing(blessing)
+    }
+}
+
+impl From<BoostItemTypes> for GainSource {
+    fn from(item: BoostItemTypes) -> Self {
+        GainSource::BoostItem(item)
+    }
+}
+
+impl From<ChallengeTypes> for GainSource {
+    fn from(challenge: ChallengeTypes) -> Self {
+        GainSource::Challenge(challenge)
+    }
+}
+
+impl From<DeityTypes> for GainSource {
+    fn from(deity: DeityTypes) -> Self {
+        GainSource::Deity(deity)
+    }
+}
+
+impl From<HousingTypes> for GainSource {
+    fn from(housing: HousingTypes) -> Self {
+        GainSource::Housing(housing)
+    }
+}
+
+impl From<KarmaUpgradeTypes> for GainSource {
+    fn from(upgrade: KarmaUpgradeTypes) -> Self {
+        GainSource::KarmaUpgrade(upgrade)
+    }
+}
+
+impl From<LifeEventTypes> for GainSource {
+    fn from(life_event: LifeEventTypes) -> Self {
+        GainSource::LifeEvent(life_event)
+    }
+}
+
+impl From<RebirthUpgradeTypes> for GainSource {
+    fn from(upgrade: RebirthUpgradeTypes) -> Self {
+        GainSource::RebirthUpgrade(upgrade)
+    }
+}
+
+impl From<SkillTypes> for GainSource {
+    fn from(skill: SkillTypes) -> Self {
+        GainSource::Skill(skill)
+    }
+}
+
+impl From<StatTypes> for GainSource {
+    fn from(stat: StatTypes) -> Self {
+        GainSource::Stat(stat)
+    }
+}
+
+impl From<TombTypes> for GainSource {
+    fn from(tomb: TombTypes) -> Self {
+        GainSource::Tomb(tomb)
+    }
+}
+
+impl From<WorkTypes> for GainSource {
+    fn from(work: WorkTypes) -> Self {
+        GainSource::Work(work)
+    }
+}
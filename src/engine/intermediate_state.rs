@@ -1,8 +1,16 @@
-use super::value_keys::KeyValues;
-use serde::Serialize;
-use std::collections::HashMap;
-
-#[derive(Serialize, Debug)]
+use super::gain_source::GainSource;
+use super::modifier_sources::ModifierSources;
+use super::value_keys::{KeyValues, KEY_VALUES_SIZE};
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+// Room reserved up front so rebuilding the modifiers doesn't allocate
+const BASES_CAPACITY: usize = 4;
+const MULTIPLIERS_CAPACITY: usize = 16;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
 pub struct ValueGains {
     pub key: KeyValues,
     pub bases: Vec<Base>,
@@ -13,11 +21,20 @@ impl ValueGains {
     pub fn new(name: KeyValues) -> ValueGains {
         ValueGains {
             key: name,
-            bases: Vec::new(),
-            multipliers: Vec::new(),
+            bases: Vec::with_capacity(BASES_CAPACITY),
+            multipliers: Vec::with_capacity(MULTIPLIERS_CAPACITY),
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty() && self.multipliers.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bases.clear();
+        self.multipliers.clear();
+    }
+
     pub fn calculate_value(&self) -> f64 {
         let sum = self
             .multipliers
@@ -32,23 +49,62 @@ impl ValueGains {
             .iter()
             .fold(1.0, |acc: f64, elem: &Multiplier| acc * elem.factor)
     }
+
+    // The value once every life event has worn off
+    pub fn calculate_lasting_value(&self) -> f64 {
+        let lasting = |source: &GainSource| !matches!(source, GainSource::LifeEvent(_));
+        let multiplier = self
+            .multipliers
+            .iter()
+            .filter(|m| lasting(&m.source))
+            .fold(1.0, |acc: f64, elem: &Multiplier| acc * elem.factor);
+        let base: f64 = self
+            .bases
+            .iter()
+            .filter(|b| lasting(&b.source))
+            .map(|b| b.base)
+            .sum();
+        base * multiplier
+    }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
 pub struct Base {
-    base: f64,
-    source_descriptor: &'static str,
+    pub base: f64,
+    pub source: GainSource,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
 pub struct Multiplier {
-    factor: f64,
-    source_descriptor: &'static str,
+    pub factor: f64,
+    pub source: GainSource,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct BreakdownEntry {
+    pub amount: f64,
+    pub source: GainSource,
+    pub display_name: String,
+}
+
+// Everything that makes up a value, for showing where it comes from
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ValueBreakdown {
+    pub key: KeyValues,
+    pub value: f64,
+    pub multiplier: f64,
+    pub bases: Vec<BreakdownEntry>,
+    pub multipliers: Vec<BreakdownEntry>,
 }
 
 #[derive(Serialize, Debug)]
 pub struct IntermediateState {
-    pub value_gains: HashMap<KeyValues, ValueGains>,
+    // Indexed by KeyValues
+    #[serde(serialize_with = "serialize_value_gains")]
+    pub value_gains: [ValueGains; KEY_VALUES_SIZE],
+    // What the modifiers were built from, None when they need a rebuild
+    #[serde(skip)]
+    sources: Option<ModifierSources>,
 }
 
 impl Default for IntermediateState {
@@ -60,58 +116,102 @@ impl Default for IntermediateState {
 impl IntermediateState {
     pub fn new() -> IntermediateState {
         IntermediateState {
-            value_gains: HashMap::new(),
+            value_gains: get_value_gains(),
+            sources: None,
+        }
+    }
+
+    // Removes all modifiers but keeps their storage
+    pub fn clear(&mut self) {
+        for value_gains in self.value_gains.iter_mut() {
+            value_gains.clear();
         }
+        self.sources = None;
+    }
+
+    pub fn is_up_to_date(&self, sources: &ModifierSources) -> bool {
+        self.sources.as_ref() == Some(sources)
     }
 
-    pub fn get_value(&mut self, key: KeyValues) -> f64 {
-        self.value_gains
-            .get(&key)
-            .map(|value_gains| value_gains.calculate_value())
-            .unwrap_or(0.0)
+    pub fn set_sources(&mut self, sources: ModifierSources) {
+        self.sources = Some(sources);
+    }
+
+    // Forces the modifiers to be rebuilt on the next tick
+    pub fn invalidate(&mut self) {
+        self.sources = None;
+    }
+
+    pub fn get_value(&self, key: KeyValues) -> f64 {
+        self.value_gains[key as usize].calculate_value()
     }
 
     pub fn get_multiplier(&self, key: KeyValues) -> f64 {
-        self.value_gains
-            .get(&key)
-            .map(|value_gains| value_gains.calculate_muliplier())
-            .unwrap_or(1.0)
+        self.value_gains[key as usize].calculate_muliplier()
+    }
+
+    pub fn get_lasting_value(&self, key: KeyValues) -> f64 {
+        self.value_gains[key as usize].calculate_lasting_value()
     }
 
     pub fn get_gains<T: Gain>(&mut self, source: &T) {
         source.gain(self);
     }
 
-    pub fn add_multiplier(&mut self, key: KeyValues, factor: f64, source_descriptor: &'static str) {
-        let values = self
-            .value_gains
-            .entry(key)
-            .or_insert_with(|| ValueGains::new(key));
-        values.multipliers.push(Multiplier {
+    pub fn get_breakdown(&self, key: KeyValues) -> ValueBreakdown {
+        let value_gains = &self.value_gains[key as usize];
+        ValueBreakdown {
+            key,
+            value: value_gains.calculate_value(),
+            multiplier: value_gains.calculate_muliplier(),
+            bases: value_gains
+                .bases
+                .iter()
+                .map(|base| BreakdownEntry {
+                    amount: base.base,
+                    source: base.source,
+                    display_name: base.source.display_name(),
+                })
+                .collect(),
+            multipliers: value_gains
+                .multipliers
+                .iter()
+                .map(|multiplier| BreakdownEntry {
+                    amount: multiplier.factor,
+                    source: multiplier.source,
+                    display_name: multiplier.source.display_name(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn add_multiplier<S: Into<GainSource>>(&mut self, key: KeyValues, factor: f64, source: S) {
+        self.value_gains[key as usize].multipliers.push(Multiplier {
             factor,
-            source_descriptor,
+            source: source.into(),
         });
     }
 
-    pub fn set_base(&mut self, key: KeyValues, base: f64, source_descriptor: &'static str) {
-        let values = self
-            .value_gains
-            .entry(key)
-            .or_insert_with(|| ValueGains::new(key));
-        values.bases = vec![Base {
+    pub fn set_base<S: Into<GainSource>>(&mut self, key: KeyValues, base: f64, source: S) {
+        let bases = &mut self.value_gains[key as usize].bases;
+        bases.clear();
+        bases.push(Base {
             base,
-            source_descriptor,
-        }];
+            source: source.into(),
+        });
     }
 
-    pub fn add_base(&mut self, key: KeyValues, base: f64, source_descriptor: &'static str) {
-        let values = self
-            .value_gains
-            .entry(key)
-            .or_insert_with(|| ValueGains::new(key));
-        values.bases.push(Base {
+    pub fn remove_base<S: Into<GainSource>>(&mut self, key: KeyValues, source: S) {
+        let source = source.into();
+        self.value_gains[key as usize]
+            .bases
+            .retain(|base| base.source != source);
+    }
+
+    pub fn add_base<S: Into<GainSource>>(&mut self, key: KeyValues, base: f64, source: S) {
+        self.value_gains[key as usize].bases.push(Base {
             base,
-            source_descriptor,
+            source: source.into(),
         });
     }
 }
@@ -119,3 +219,25 @@ impl IntermediateState {
 pub trait Gain {
     fn gain(&self, intermediate: &mut IntermediateState);
 }
+
+pub fn get_value_gains() -> [ValueGains; KEY_VALUES_SIZE] {
+    let mut value_gains: [MaybeUninit<ValueGains>; KEY_VALUES_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for key in KeyValues::iter() {
+        value_gains[key as usize].write(ValueGains::new(key));
+    }
+    unsafe { mem::transmute(value_gains) }
+}
+
+// Only the keys that have any modifiers, as a map like the old HashMap storage
+fn serialize_value_gains<S: Serializer>(
+    value_gains: &[ValueGains; KEY_VALUES_SIZE],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let non_empty = value_gains.iter().filter(|gains| !gains.is_empty());
+    let mut map = serializer.serialize_map(None)?;
+    for gains in non_empty {
+        map.serialize_entry(&gains.key, gains)?;
+    }
+    map.end()
+}
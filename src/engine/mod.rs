@@ -1,21 +1,30 @@
 pub mod auto_functions;
+pub mod gain_source;
 pub mod intermediate_state;
+pub mod modifier_sources;
+pub mod projection;
+pub mod timestep;
 pub mod value_keys;
 
 use crate::engine::value_keys::KeyValues;
+use crate::events::GameEventType;
 use crate::game::Game;
 use crate::input::activity::ActivityTypes;
 use crate::input::blessing::BlessingTypes;
 use crate::input::boost_item::BoostItemTypes;
 use crate::input::housing::HousingTypes;
+use crate::input::karma_upgrade::KarmaUpgradeTypes;
+use crate::input::life_event::LifeEventTypes;
 use crate::input::rebirth_upgrade::RebirthUpgradeTypes;
 use crate::input::skill::SkillTypes;
 use crate::input::stat::StatTypes;
 use crate::input::tomb::TombTypes;
 use crate::input::work::WorkTypes;
+use crate::state::life_history::LifeSummary;
 use crate::state::skill::Skill;
 use crate::state::stats::Stat;
 use crate::state::work::Work as StateWork;
+use crate::world_content::achievement::check_achievements;
 use crate::world_content::activity::{
     should_be_visible_activity, should_unlock_activity, translate_activity,
 };
@@ -26,7 +35,14 @@ use crate::world_content::blessing::{
 use crate::world_content::boost_item::{
     should_be_visible_boost_item, should_unlock_boost_item, translate_boost_item,
 };
-use crate::world_content::housing::{should_be_visible_housing, should_unlock_housing};
+use crate::world_content::challenge::check_challenge_completion;
+use crate::world_content::housing::{
+    should_be_visible_housing, should_unlock_housing, Housing as WorldHousing,
+};
+use crate::world_content::karma_upgrade::{
+    calculate_karma_gain, should_be_visible_karma_upgrade, should_unlock_karma_upgrade,
+};
+use crate::world_content::life_event::{roll_life_event, LIFE_EVENT_INTERVAL};
 use crate::world_content::rebirth_upgrade::{
     should_be_visible_rebirth_upgrade, should_unlock_rebirth_upgrade, unlock,
 };
@@ -36,8 +52,10 @@ use crate::world_content::tomb::{should_be_visible_tomb, should_unlock_tomb};
 use crate::world_content::work::{should_be_visible_work, should_unlock_work, Work as WorkWorld};
 use crate::TICK_RATE;
 use crate::WORLD;
-use intermediate_state::IntermediateState;
+use gain_source::GainSource;
+use modifier_sources::ModifierSources;
 use strum::IntoEnumIterator;
+use timestep::pick_step_ticks;
 
 use self::auto_functions::{
     auto_buy_blessing, auto_buy_item, auto_buy_queued_item, auto_buy_tomb, auto_living, auto_work,
@@ -49,44 +67,70 @@ pub fn engine_run(game: &mut Game) {
     }
     let run_count = game.meta_data.handle_run_count();
     for _ in 0..run_count {
-        internal_run(game);
+        internal_run(game, 1);
+    }
+}
+
+// Runs one step of at most max_ticks ticks and returns how many ticks it took.
+// See timestep.rs for how the step length is chosen.
+pub fn engine_step(game: &mut Game, max_ticks: u32) -> u32 {
+    if game.state.life_stats.dead || max_ticks == 0 {
+        return 0;
+    }
+    internal_run(game, max_ticks)
+}
+
+// Simulates up to `ticks` ticks in coarse steps, stopping early if the character
+// starts dying. Returns the number of ticks simulated.
+pub fn engine_run_ticks(game: &mut Game, ticks: u32) -> u32 {
+    let mut ran = 0;
+    while ran < ticks && !game.state.life_stats.is_dying {
+        let step = engine_step(game, ticks - ran);
+        if step == 0 {
+            break;
+        }
+        ran += step;
     }
+    ran
 }
 
-fn internal_run(game: &mut Game) {
-    game.intermediate_state = calculate_intermediate_state(game);
-    let _old_state = game.state.clone(); //TODO use for delta?
+fn internal_run(game: &mut Game, max_ticks: u32) -> u32 {
     if game.state.life_stats.replaying {
         game.replay_input();
     }
-    game.state.life_stats.current_tick += 1;
 
     auto_input_update(game);
-    // Apply all modifiers to intermediate
-    apply_housing(game);
-    apply_items(game);
-    apply_work(game);
-    apply_activities(game);
-    apply_tombs(game);
-    apply_stats(game);
-    apply_blessings(game);
-    apply_skills(game);
-    apply_rebirth_upgrades(game);
+    update_life_events(game);
+    let housing = current_housing(game);
+    update_eviction(game, housing);
+    let sources = ModifierSources::new(game, housing.name);
+    if !game.intermediate_state.is_up_to_date(&sources) {
+        calculate_intermediate_state(game, housing);
+        game.intermediate_state.set_sources(sources);
+    }
+    // Depends on the money, which changes every tick
+    apply_commissions(game);
 
-    apply_active_work(game);
+    let ticks = pick_step_ticks(game, housing.upkeep, max_ticks);
+    game.state.life_stats.current_tick += ticks;
+    let ticks_f = ticks as f64;
 
     // Get the gains
+    pay_upkeep(housing, game, ticks_f);
     calculate_works_income(game);
-    calculate_divine_favor(game);
+    calculate_divine_favor(game, ticks_f);
     calculate_tombs_income(game);
-    do_work(game.input.work, game);
-    gain_work_xp(game);
-    gain_stat_xp(game);
-    gain_skill_xp(game);
+    do_work(game.input.work, game, ticks_f);
+    gain_work_xp(game, ticks_f);
+    gain_stat_xp(game, ticks_f);
+    gain_skill_xp(game, ticks_f);
 
     // update frontend read values
     update_unlocks(game);
-    update_life_stats(game);
+    update_life_stats(game, ticks_f);
+    check_achievements(game);
+    game.sampler.record(&game.state);
+    ticks
 }
 
 fn auto_input_update(game: &mut Game) {
@@ -114,22 +158,39 @@ fn get_happiness(game: &Game) -> f64 {
     game.intermediate_state.get_multiplier(KeyValues::Happiness)
 }
 
-fn update_life_stats(game: &mut Game) {
+// Base gamespeed is that one life should take 30min for 0.0 health,
+// the game runs in 30 ticks/second
+// Days/tick = total_days / (ticks in 30 min)
+// 52*365/(30*60*30) = 0.351
+pub(crate) fn days_per_tick(game: &Game) -> f64 {
+    let time_progression = 52.0 * 365.0 / (30.0 * 60.0 * TICK_RATE);
+    time_progression * game.intermediate_state.get_value(KeyValues::TimeFactor)
+}
+
+// Health gained per second before it slows down near full health
+pub(crate) fn base_health_rate(health: f64) -> f64 {
+    0.000_08 * health
+}
+
+// Health gained per second, gains slow down as health gets closer to 1
+pub(crate) fn health_rate(base_health_rate: f64, health: f64) -> f64 {
+    if health > 0.0 && base_health_rate > 0.0 {
+        base_health_rate * (1.0 - health).powi(2)
+    } else {
+        base_health_rate
+    }
+}
+
+fn update_life_stats(game: &mut Game, ticks: f64) {
     game.state.life_stats.happiness = get_happiness(game);
+    let days = days_per_tick(game) * ticks;
+    let base_health_rate = base_health_rate(game.intermediate_state.get_value(KeyValues::Health));
     let life_stats = &mut game.state.life_stats;
-    let mut health_rate = 0.000_08 * game.intermediate_state.get_value(KeyValues::Health);
-    if life_stats.health > 0.0 && health_rate > 0.0 {
-        health_rate *= (1.0 - life_stats.health).powi(2);
-    }
+    let health_rate = health_rate(base_health_rate, life_stats.health);
     life_stats.health_rate = health_rate;
-    life_stats.health += health_rate / TICK_RATE;
+    life_stats.health += health_rate * ticks / TICK_RATE;
 
-    // Base gamespeed is that one life should take 30min for 0.0 health,
-    // the game runs in 30 ticks/second
-    // Days/tick = total_days / (ticks in 30 min)
-    // 52*365/(30*60*30) = 0.351
-    let time_progression = 52.0 * 365.0 / (30.0 * 60.0 * TICK_RATE);
-    life_stats.age += time_progression * game.state.rebirth_stats.time_factor;
+    life_stats.age += days;
     life_stats.lifespan = crate::BASE_LIFESPAN * (1.0 + life_stats.health);
 
     let should_die = life_stats.age + life_stats.health >= life_stats.lifespan;
@@ -145,12 +206,19 @@ pub fn update_unlocks(game: &mut Game) {
         }
     }
     for work in WorkTypes::iter() {
-        game.state.works[work as usize].is_unlocked = should_unlock_work(work, game);
+        let is_unlocked = should_unlock_work(work, game);
+        if is_unlocked && !game.state.works[work as usize].is_unlocked {
+            push_unlock_event(game, GameEventType::WorkUnlocked { work });
+        }
+        game.state.works[work as usize].is_unlocked = is_unlocked;
         game.state.works[work as usize].is_visible = should_be_visible_work(work, game);
     }
     for activity in ActivityTypes::iter() {
-        game.state.activities[activity as usize].is_unlocked =
-            should_unlock_activity(activity, game);
+        let is_unlocked = should_unlock_activity(activity, game);
+        if is_unlocked && !game.state.activities[activity as usize].is_unlocked {
+            push_unlock_event(game, GameEventType::ActivityUnlocked { activity });
+        }
+        game.state.activities[activity as usize].is_unlocked = is_unlocked;
         game.state.activities[activity as usize].is_visible =
             should_be_visible_activity(activity, game);
     }
@@ -166,6 +234,12 @@ pub fn update_unlocks(game: &mut Game) {
         game.state.rebirth_stats.rebirth_upgrades[rebirth_upgrade as usize].is_visible =
             should_be_visible_rebirth_upgrade(rebirth_upgrade, game);
     }
+    for karma_upgrade in KarmaUpgradeTypes::iter() {
+        game.state.rebirth_stats.karma_upgrades[karma_upgrade as usize].is_unlocked =
+            should_unlock_karma_upgrade(karma_upgrade, game);
+        game.state.rebirth_stats.karma_upgrades[karma_upgrade as usize].is_visible =
+            should_be_visible_karma_upgrade(karma_upgrade, game);
+    }
     for tomb in TombTypes::iter() {
         game.state.tombs[tomb as usize].is_unlocked = should_unlock_tomb(tomb, game);
         game.state.tombs[tomb as usize].is_visible = should_be_visible_tomb(tomb, game);
@@ -189,31 +263,111 @@ pub fn update_unlocks(game: &mut Game) {
     for housing in HousingTypes::iter() {
         // Don't make invisible after once being made visible
         game.state.housing[housing as usize].is_visible = should_be_visible_housing(housing, game);
-        game.state.housing[housing as usize].is_unlocked = should_unlock_housing(housing, game);
+        let is_unlocked = should_unlock_housing(housing, game);
+        if is_unlocked && !game.state.housing[housing as usize].is_unlocked {
+            push_unlock_event(game, GameEventType::HousingUnlocked { housing });
+        }
+        game.state.housing[housing as usize].is_unlocked = is_unlocked;
+    }
+}
+
+// Everything basic unlocks on the first tick of a life, that's not news
+fn push_unlock_event(game: &mut Game, event: GameEventType) {
+    if game.state.life_stats.current_tick > 1 {
+        game.push_event(event);
     }
 }
 
-fn calculate_intermediate_state(_game: &Game) -> IntermediateState {
-    IntermediateState::new()
+// Rebuilds all modifiers from scratch
+pub fn calculate_intermediate_state(game: &mut Game, housing: &WorldHousing) {
+    game.intermediate_state.clear();
+    game.intermediate_state.set_base(
+        KeyValues::TimeFactor,
+        game.state.rebirth_stats.time_factor,
+        GainSource::Base,
+    );
+    // Apply all modifiers to intermediate
+    game.intermediate_state.get_gains(housing);
+    apply_items(game);
+    apply_work(game);
+    apply_activities(game);
+    apply_tombs(game);
+    apply_stats(game);
+    apply_blessings(game);
+    apply_deity(game);
+    apply_skills(game);
+    apply_rebirth_upgrades(game);
+    apply_karma_upgrades(game);
+    apply_life_events(game);
+    apply_achievements(game);
+    apply_challenges(game);
+
+    apply_active_work(game);
 }
 
 pub fn character_death_update(game: &mut Game) {
     game.state.life_stats.dead = true;
+    check_achievements(game);
+    let coins_gain = game.intermediate_state.get_value(KeyValues::Coins);
+    let karma_gain = calculate_karma_gain(&game.state)
+        * game.intermediate_state.get_multiplier(KeyValues::Karma);
+    game.push_event(GameEventType::LifeEnded {
+        age: game.state.life_stats.age,
+        coins_gain,
+        karma_gain,
+    });
+    check_challenge_completion(game, coins_gain);
     for (index, work) in game.state.works.iter().enumerate() {
         game.state.rebirth_stats.max_job_levels[index] =
             std::cmp::max(game.state.rebirth_stats.max_job_levels[index], work.level);
     }
-    game.state.rebirth_stats.coins += game.intermediate_state.get_value(KeyValues::Coins);
+    let summary = LifeSummary::new(&game.state, coins_gain);
+    game.state.rebirth_stats.history.push(summary);
+    game.state.rebirth_stats.coins += coins_gain;
+    game.state.rebirth_stats.karma += karma_gain;
 }
 
-fn apply_housing(game: &mut Game) {
-    let mut housing = WORLD.get_housing(game.input.housing);
+pub(crate) fn current_housing(game: &Game) -> &'static WorldHousing {
+    let housing = WORLD.get_housing(game.input.housing);
     if housing.upkeep > game.state.items.money {
-        housing = WORLD.get_housing(HousingTypes::StoneFloor);
-        // TODO signal to frontend that you are out of cash?
+        return WORLD.get_housing(HousingTypes::StoneFloor);
     }
-    game.state.items.money -= housing.upkeep / TICK_RATE;
-    game.intermediate_state.get_gains(housing);
+    housing
+}
+
+fn update_eviction(game: &mut Game, housing: &WorldHousing) {
+    let evicted = housing.name != game.input.housing;
+    if evicted && !game.state.life_stats.evicted {
+        game.push_event(GameEventType::Evicted {
+            housing: game.input.housing,
+        });
+    }
+    game.state.life_stats.evicted = evicted;
+}
+
+// Wears off life events that are over and rolls for a new one every
+// LIFE_EVENT_INTERVAL ticks. The rolls only depend on the seed of the life, so a
+// replay gets the same events whatever inputs it makes.
+fn update_life_events(game: &mut Game) {
+    let tick = game.state.life_stats.current_tick;
+    for life_event in game.state.life_events.iter_mut() {
+        life_event.is_active = life_event.active_until > tick;
+    }
+    if tick == 0 || !tick.is_multiple_of(LIFE_EVENT_INTERVAL) {
+        return;
+    }
+    if let Some(life_event) = roll_life_event(&mut game.state.rng, game.state.rebirth_stats.tier) {
+        let world_life_event = &game.world.life_events[life_event as usize];
+        let state_life_event = &mut game.state.life_events[life_event as usize];
+        state_life_event.is_active = true;
+        state_life_event.active_until = tick + world_life_event.duration;
+        state_life_event.times_happened += 1;
+        game.push_event(GameEventType::LifeEventHappened { life_event });
+    }
+}
+
+fn pay_upkeep(housing: &WorldHousing, game: &mut Game, ticks: f64) {
+    game.state.items.money -= housing.upkeep * ticks / TICK_RATE;
 }
 
 fn apply_items(game: &mut Game) {
@@ -240,10 +394,10 @@ fn calculate_works_income(game: &mut Game) {
     }
 }
 
-fn calculate_divine_favor(game: &mut Game) {
+fn calculate_divine_favor(game: &mut Game, ticks: f64) {
     let divine_favor_rate = game.intermediate_state.get_value(KeyValues::DivineFavor);
     game.state.items.divine_favor_rate = divine_favor_rate;
-    game.state.items.divine_favor += divine_favor_rate / TICK_RATE;
+    game.state.items.divine_favor += divine_favor_rate * ticks / TICK_RATE;
 }
 
 fn apply_work(game: &mut Game) {
@@ -253,26 +407,71 @@ fn apply_work(game: &mut Game) {
         }
         let work = WORLD.get_work(work_state.name);
 
-        let main_stat_level = game.state.stats[StatTypes::from(work.work_type) as usize].level;
+        let main_stat = StatTypes::from(work.work_type);
+        let main_stat_level = game.state.stats[main_stat as usize].level;
         let stat_multiplier: f64 = 1.0 + (main_stat_level / 10.0);
         game.intermediate_state
-            .add_multiplier(work.name.into(), stat_multiplier, "Stat");
+            .add_multiplier(work.name.into(), stat_multiplier, main_stat);
 
         let level_multiplier: f64 = 1.0 + (work_state.level as f64 / 10.0);
+        game.intermediate_state.add_multiplier(
+            work.name.into(),
+            level_multiplier,
+            GainSource::WorkLevel(work.name),
+        );
+        game.intermediate_state
+            .set_base(work.name.into(), work.money, work.name);
+    }
+}
+// Merchant works earn a share of the money on hand on top of their flat income.
+// The other modifiers are cached, so only the commission of the last tick is
+// replaced.
+fn apply_commissions(game: &mut Game) {
+    let money = game.state.items.money.max(0.0);
+    for work_state in game.state.works.iter() {
+        let work = WORLD.get_work(work_state.name);
+        if !work_state.is_visible || work.commission == 0.0 {
+            continue;
+        }
+        let source = GainSource::Commission(work.name);
         game.intermediate_state
-            .add_multiplier(work.name.into(), level_multiplier, "Level");
+            .remove_base(work.name.into(), source);
         game.intermediate_state
-            .set_base(work.name.into(), work.money, work.display_name);
+            .add_base(work.name.into(), work.commission * money, source);
+    }
+}
+
+fn apply_achievements(game: &mut Game) {
+    for achievement in WORLD.achievements.iter() {
+        achievement.get_achievement_gains(game);
+    }
+}
+
+fn apply_challenges(game: &mut Game) {
+    for challenge in WORLD.challenges.iter() {
+        challenge.get_challenge_gains(game);
+    }
+}
+
+fn apply_life_events(game: &mut Game) {
+    for life_event in LifeEventTypes::iter() {
+        if game.state.life_events[life_event as usize].is_active {
+            game.intermediate_state
+                .get_gains(&game.world.life_events[life_event as usize]);
+        }
     }
 }
+
 fn apply_active_work(game: &mut Game) {
     let work = WORLD.get_work(game.input.work);
     game.intermediate_state.get_gains(work);
 }
 
 fn apply_activities(game: &mut Game) {
-    let activity = translate_activity(game.input.activity);
-    game.intermediate_state.get_gains(&activity);
+    for (activity_type, share) in game.input.activity_shares() {
+        let activity = translate_activity(activity_type);
+        activity.gain_share(&mut game.intermediate_state, share);
+    }
 }
 
 fn apply_tombs(game: &mut Game) {
@@ -290,6 +489,12 @@ fn apply_stats(game: &mut Game) {
     }
 }
 
+fn apply_deity(game: &mut Game) {
+    for deity in WORLD.deities.iter() {
+        deity.get_deity_gains(game);
+    }
+}
+
 fn apply_blessings(game: &mut Game) {
     for skill in WORLD.blessings.iter() {
         skill.get_blessings_gains(game);
@@ -308,78 +513,124 @@ fn apply_rebirth_upgrades(game: &mut Game) {
     }
 }
 
-fn gain_work_xp(game: &mut Game) {
+fn apply_karma_upgrades(game: &mut Game) {
+    for upgrade in WORLD.karma_upgrades.iter() {
+        upgrade.get_upgrade_gains(game);
+    }
+}
+
+// Work XP per second for the active work
+pub(crate) fn work_xp_rate(game: &Game) -> f64 {
     let input_work = game.input.work as usize;
-    let work: &mut StateWork = &mut game.state.works[input_work];
     let work_world = game.world.get_work(game.input.work);
-    work.next_level_progress += 10.0
-        * game.state.life_stats.happiness
+    10.0 * game.state.life_stats.happiness
         * (1.0 + f64::sqrt(game.state.rebirth_stats.max_job_levels[input_work] as f64))
         * game
             .intermediate_state
             .get_multiplier(work_world.work_type.into())
-        / TICK_RATE;
+}
+
+fn gain_work_xp(game: &mut Game, ticks: f64) {
+    let xp_rate = work_xp_rate(game);
+    let input_work = game.input.work as usize;
+    let work: &mut StateWork = &mut game.state.works[input_work];
+    let work_world = game.world.get_work(game.input.work);
+    work.next_level_progress += xp_rate * ticks / TICK_RATE;
     let mut next_level_xp_needed = calculate_work_next_level_xp_needed(work, work_world);
     while work.next_level_progress > next_level_xp_needed {
         work.level += 1;
         work.next_level_progress -= next_level_xp_needed;
         next_level_xp_needed = calculate_work_next_level_xp_needed(work, work_world);
+        game.events.push(
+            game.state.life_stats.current_tick,
+            GameEventType::WorkLevelUp {
+                work: work.name,
+                level: work.level,
+            },
+        );
     }
     work.next_level_required = next_level_xp_needed;
     work.next_level_percentage = (work.next_level_progress * 100.0) / next_level_xp_needed;
 }
 
-fn gain_skill_xp(game: &mut Game) {
-    let level_multiplier = game.intermediate_state.get_multiplier(KeyValues::Skills);
+// Skill XP per second, only hidden skills don't train
+pub(crate) fn skill_xp_rate(game: &Game, skill_type: SkillTypes) -> f64 {
+    let skill = &WORLD.skills[skill_type as usize];
+    game.intermediate_state.get_value(skill.xp_source)
+        * skill.xp_factor
+        * game.intermediate_state.get_multiplier(KeyValues::Skills)
+}
+
+fn gain_skill_xp(game: &mut Game, ticks: f64) {
     for skill_type in SkillTypes::iter() {
-        let skill_xp = game.intermediate_state.get_value(skill_type.into()) * level_multiplier;
+        let skill_xp = skill_xp_rate(game, skill_type);
         let skill: &mut Skill = &mut game.state.skills[skill_type as usize];
         let world_skill = &game.world.skills[skill_type as usize];
         skill.xp_rate = skill_xp;
-        skill.next_level_progress += skill_xp / TICK_RATE;
+        skill.next_level_progress += skill_xp * ticks / TICK_RATE;
         let mut next_level_xp_needed = calculate_skill_next_level_xp_needed(skill, world_skill);
         while skill.next_level_progress > next_level_xp_needed {
             skill.level += 1.0;
             skill.next_level_progress -= next_level_xp_needed;
             next_level_xp_needed = calculate_skill_next_level_xp_needed(skill, world_skill);
+            game.events.push(
+                game.state.life_stats.current_tick,
+                GameEventType::SkillLevelUp {
+                    skill: skill_type,
+                    level: skill.level,
+                },
+            );
         }
         skill.next_level_required = next_level_xp_needed;
         skill.next_level_percentage = (skill.next_level_progress * 100.0) / next_level_xp_needed;
     }
 }
 
-fn calculate_skill_next_level_xp_needed(skill: &mut Skill, world_skill: &WorldSkill) -> f64 {
+pub(crate) fn calculate_skill_next_level_xp_needed(skill: &Skill, world_skill: &WorldSkill) -> f64 {
     (100.0 + (4.0 * skill.level * skill.level)) * world_skill.xp_req_modifier
 }
 
-fn gain_stat_xp(game: &mut Game) {
-    let level_multiplier = game.intermediate_state.get_multiplier(KeyValues::Stats);
+// Stat XP per second
+pub(crate) fn stat_xp_rate(game: &Game, stat_type: StatTypes) -> f64 {
+    game.intermediate_state.get_value(stat_type.into())
+        * game.intermediate_state.get_multiplier(KeyValues::Stats)
+}
+
+fn gain_stat_xp(game: &mut Game, ticks: f64) {
     for stat_type in StatTypes::iter() {
-        let stat_xp = game.intermediate_state.get_value(stat_type.into()) * level_multiplier;
+        let stat_xp = stat_xp_rate(game, stat_type);
         let stat: &mut Stat = &mut game.state.stats[stat_type as usize];
         stat.xp_rate = stat_xp;
-        stat.next_level_progress += stat_xp / TICK_RATE;
+        stat.next_level_progress += stat_xp * ticks / TICK_RATE;
         let mut next_level_xp_needed = calculate_stat_next_level_xp_needed(stat);
         while stat.next_level_progress > next_level_xp_needed {
             stat.level += 1.0;
             stat.next_level_progress -= next_level_xp_needed;
             next_level_xp_needed = calculate_stat_next_level_xp_needed(stat);
+            game.events.push(
+                game.state.life_stats.current_tick,
+                GameEventType::StatLevelUp {
+                    stat: stat_type,
+                    level: stat.level,
+                },
+            );
         }
         stat.next_level_required = next_level_xp_needed;
         stat.next_level_percentage = (stat.next_level_progress * 100.0) / next_level_xp_needed;
     }
 }
 
-fn calculate_stat_next_level_xp_needed(stat: &mut Stat) -> f64 {
+pub(crate) fn calculate_stat_next_level_xp_needed(stat: &Stat) -> f64 {
     100.0 + (4.0 * stat.level * stat.level)
 }
 
-fn calculate_work_next_level_xp_needed(work: &mut StateWork, work_world: &WorkWorld) -> f64 {
+pub(crate) fn calculate_work_next_level_xp_needed(work: &StateWork, work_world: &WorkWorld) -> f64 {
     (100 + (4 * work.level * work.level)) as f64 * work_world.xp_req_modifier
 }
 
-fn do_work(input_work: WorkTypes, game: &mut Game) {
+fn do_work(input_work: WorkTypes, game: &mut Game, ticks: f64) {
     let income = game.intermediate_state.get_value(input_work.into());
     game.state.items.income = income;
-    game.state.items.money += income / TICK_RATE;
+    game.state.items.money += income * ticks / TICK_RATE;
+    game.state.items.max_money = game.state.items.max_money.max(game.state.items.money);
 }
@@ -0,0 +1,69 @@
+use crate::game::Game;
+use crate::input::achievement::ACHIEVEMENT_SIZE;
+use crate::input::activity::{ActivityTypes, ACTIVITY_SIZE};
+use crate::input::blessing::BLESSING_SIZE;
+use crate::input::boost_item::BOOST_ITEM_SIZE;
+use crate::input::challenge::CHALLENGE_SIZE;
+use crate::input::deity::DeityTypes;
+use crate::input::housing::HousingTypes;
+use crate::input::karma_upgrade::KARMA_UPGRADE_SIZE;
+use crate::input::life_event::LIFE_EVENT_SIZE;
+use crate::input::rebirth_upgrade::REBIRTH_UPGRADE_SIZE;
+use crate::input::skill::SKILL_SIZE;
+use crate::input::stat::STAT_SIZE;
+use crate::input::tomb::TOMB_SIZE;
+use crate::input::work::{WorkTypes, WORK_SIZE};
+use std::array::from_fn;
+
+// Everything the apply_* passes read from the game. As long as this stays the
+// same, so do the modifiers in IntermediateState and they don't need to be
+// rebuilt. It's built every tick, so it only holds fixed size arrays and
+// doesn't allocate.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ModifierSources {
+    housing: HousingTypes,
+    work: WorkTypes,
+    activity: ActivityTypes,
+    activity_weights: [u32; ACTIVITY_SIZE],
+    deity: Option<DeityTypes>,
+    tier: u32,
+    time_factor: f64,
+    boost_items: [bool; BOOST_ITEM_SIZE],
+    tombs: [bool; TOMB_SIZE],
+    rebirth_upgrades: [bool; REBIRTH_UPGRADE_SIZE],
+    karma_upgrades: [bool; KARMA_UPGRADE_SIZE],
+    works: [(bool, u32); WORK_SIZE],
+    stats: [(bool, f64); STAT_SIZE],
+    skills: [(bool, f64); SKILL_SIZE],
+    blessings: [(bool, u32); BLESSING_SIZE],
+    life_events: [bool; LIFE_EVENT_SIZE],
+    achievements: [bool; ACHIEVEMENT_SIZE],
+    challenges: [bool; CHALLENGE_SIZE],
+}
+
+impl ModifierSources {
+    pub fn new(game: &Game, housing: HousingTypes) -> ModifierSources {
+        let state = &game.state;
+        let rebirth_stats = &state.rebirth_stats;
+        ModifierSources {
+            housing,
+            work: game.input.work,
+            activity: game.input.activity,
+            activity_weights: game.input.activity_weights,
+            deity: game.input.deity,
+            tier: rebirth_stats.tier,
+            time_factor: rebirth_stats.time_factor,
+            boost_items: from_fn(|i| state.boost_items[i].is_purchased),
+            tombs: from_fn(|i| state.tombs[i].is_purchased),
+            rebirth_upgrades: from_fn(|i| rebirth_stats.rebirth_upgrades[i].is_purchased),
+            karma_upgrades: from_fn(|i| rebirth_stats.karma_upgrades[i].is_purchased),
+            works: from_fn(|i| (state.works[i].is_visible, state.works[i].level)),
+            stats: from_fn(|i| (state.stats[i].is_visible, state.stats[i].level)),
+            skills: from_fn(|i| (state.skills[i].is_visible, state.skills[i].level)),
+            blessings: from_fn(|i| (state.blessings[i].is_visible, state.blessings[i].level)),
+            life_events: from_fn(|i| state.life_events[i].is_active),
+            achievements: from_fn(|i| rebirth_stats.achievements[i].is_unlocked),
+            challenges: from_fn(|i| rebirth_stats.challenges[i].is_completed),
+        }
+    }
+}
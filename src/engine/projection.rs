@@ -0,0 +1,184 @@
+use super::timestep::{ticks_to_cross, ticks_to_exceed};
+use super::{
+    base_health_rate, calculate_skill_next_level_xp_needed, calculate_stat_next_level_xp_needed,
+    calculate_work_next_level_xp_needed, current_housing, days_per_tick, skill_xp_rate,
+    stat_xp_rate, work_xp_rate,
+};
+use crate::engine::value_keys::KeyValues;
+use crate::game::Game;
+use crate::input::blessing::BlessingTypes;
+use crate::input::boost_item::BoostItemTypes;
+use crate::input::housing::HousingTypes;
+use crate::input::skill::SkillTypes;
+use crate::input::stat::StatTypes;
+use crate::input::tomb::TombTypes;
+use crate::input::work::WorkTypes;
+use crate::{BASE_LIFESPAN, TICK_RATE};
+use serde::{Deserialize, Serialize};
+
+// What to estimate the time until. Works, stats and skills are estimated until
+// their next level, the others until the money or divine favor needed for them.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type", content = "id")]
+pub enum ProjectionTarget {
+    Work(WorkTypes),
+    Stat(StatTypes),
+    Skill(SkillTypes),
+    BoostItem(BoostItemTypes),
+    Tomb(TombTypes),
+    Housing(HousingTypes),
+    Blessing(BlessingTypes),
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct Projection {
+    pub ticks: u32,
+    // Real time at the current game speed
+    pub seconds: f64,
+}
+
+impl Projection {
+    fn new(game: &Game, ticks: f64) -> Option<Projection> {
+        if !ticks.is_finite() || ticks >= u32::MAX as f64 {
+            return None;
+        }
+        let game_speed = game.meta_data.game_speed.max(1) as f64;
+        Some(Projection {
+            ticks: ticks as u32,
+            seconds: ticks / TICK_RATE / game_speed,
+        })
+    }
+}
+
+// The estimate assumes the current rates stay the same, so it is exact until
+// something changes them, e.g. a purchase or another level up.
+// None when the target is never reached at the current rates.
+pub fn project(game: &Game, target: ProjectionTarget) -> Option<Projection> {
+    let ticks = match target {
+        ProjectionTarget::Work(work) => ticks_to_work_level(game, work),
+        ProjectionTarget::Stat(stat) => ticks_to_stat_level(game, stat),
+        ProjectionTarget::Skill(skill) => ticks_to_skill_level(game, skill),
+        ProjectionTarget::BoostItem(item) => {
+            if game.state.boost_items[item as usize].is_purchased {
+                return None;
+            }
+            ticks_to_money(game, game.world.boost_items[item as usize].purchasing_cost)
+        }
+        ProjectionTarget::Tomb(tomb) => {
+            if game.state.tombs[tomb as usize].is_purchased {
+                return None;
+            }
+            ticks_to_money(game, game.world.tombs[tomb as usize].purchasing_cost)
+        }
+        ProjectionTarget::Housing(housing) => {
+            ticks_to_money(game, game.world.get_housing(housing).required_money)
+        }
+        ProjectionTarget::Blessing(blessing) => ticks_to_reach(
+            game.state.items.divine_favor,
+            game.state.blessings[blessing as usize].next_level_cost,
+            game.intermediate_state.get_value(KeyValues::DivineFavor),
+        ),
+    };
+    Projection::new(game, ticks)
+}
+
+// Age in days the character dies at if nothing changes the health gain. Life
+// events wear off, so their effect on health is left out.
+pub fn predicted_death_age(game: &Game) -> f64 {
+    let life_stats = &game.state.life_stats;
+    if life_stats.is_dying {
+        return life_stats.age;
+    }
+    let rate = base_health_rate(game.intermediate_state.get_lasting_value(KeyValues::Health));
+    let days = days_per_tick(game);
+    let ticks = ticks_until_death(life_stats.age, life_stats.health, rate / TICK_RATE, days);
+    life_stats.age + ticks.ceil() * days
+}
+
+// Ticks until age + health reaches the lifespan. Health changes by rate per
+// tick, slowed down by (1 - health)^2 while both are positive, the same way
+// update_life_stats does.
+fn ticks_until_death(age: f64, health: f64, rate: f64, days: f64) -> f64 {
+    // Days left until the lifespan at the given health
+    let margin = |age: f64, health: f64| BASE_LIFESPAN * (1.0 + health) - health - age;
+    if rate <= 0.0 || health <= 0.0 {
+        // Health changes linearly
+        let closing = days - rate * (BASE_LIFESPAN - 1.0);
+        let ticks = if closing > 0.0 {
+            margin(age, health) / closing
+        } else {
+            f64::INFINITY
+        };
+        if rate <= 0.0 {
+            return ticks;
+        }
+        // Back above 0 health the gains start slowing down
+        let ticks_to_positive = -health / rate;
+        if ticks <= ticks_to_positive {
+            return ticks;
+        }
+        return ticks_to_positive
+            + ticks_until_death(age + ticks_to_positive * days, 0.0, rate, days);
+    }
+    // 1 / (1 - health) grows by rate every tick
+    let health_after = |ticks: f64| 1.0 - 1.0 / (1.0 / (1.0 - health) + rate * ticks);
+    let margin_after = |ticks: f64| margin(age + ticks * days, health_after(ticks));
+    // Health stays below 1, so the lifespan is reached before the margin at
+    // full health runs out. The margin only shrinks faster over time, so it
+    // crosses 0 once.
+    let mut low = 0.0;
+    let mut high = (margin(age, 1.0) / days).max(0.0);
+    for _ in 0..64 {
+        let middle = (low + high) / 2.0;
+        if margin_after(middle) > 0.0 {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    high
+}
+
+fn ticks_to_work_level(game: &Game, work_type: WorkTypes) -> f64 {
+    // Only the current work gains XP
+    if work_type != game.input.work {
+        return f64::INFINITY;
+    }
+    let work = &game.state.works[work_type as usize];
+    ticks_to_exceed(
+        work.next_level_progress,
+        calculate_work_next_level_xp_needed(work, game.world.get_work(work_type)),
+        work_xp_rate(game),
+    )
+}
+
+fn ticks_to_stat_level(game: &Game, stat_type: StatTypes) -> f64 {
+    let stat = &game.state.stats[stat_type as usize];
+    ticks_to_exceed(
+        stat.next_level_progress,
+        calculate_stat_next_level_xp_needed(stat),
+        stat_xp_rate(game, stat_type),
+    )
+}
+
+fn ticks_to_skill_level(game: &Game, skill_type: SkillTypes) -> f64 {
+    let skill = &game.state.skills[skill_type as usize];
+    ticks_to_exceed(
+        skill.next_level_progress,
+        calculate_skill_next_level_xp_needed(skill, &game.world.skills[skill_type as usize]),
+        skill_xp_rate(game, skill_type),
+    )
+}
+
+fn ticks_to_money(game: &Game, amount: f64) -> f64 {
+    let income = game.intermediate_state.get_value(game.input.work.into());
+    let upkeep = current_housing(game).upkeep;
+    ticks_to_reach(game.state.items.money, amount, income - upkeep)
+}
+
+fn ticks_to_reach(value: f64, threshold: f64, rate: f64) -> f64 {
+    if value >= threshold {
+        return 0.0;
+    }
+    ticks_to_cross(value, threshold, rate)
+}
@@ -0,0 +1,191 @@
+use super::{
+    calculate_skill_next_level_xp_needed, calculate_stat_next_level_xp_needed,
+    calculate_work_next_level_xp_needed, days_per_tick, skill_xp_rate, stat_xp_rate, work_xp_rate,
+};
+use crate::engine::value_keys::KeyValues;
+use crate::game::Game;
+use crate::input::housing::HousingTypes;
+use crate::input::skill::SkillTypes;
+use crate::input::stat::StatTypes;
+use crate::world_content::life_event::LIFE_EVENT_INTERVAL;
+use crate::{BASE_LIFESPAN, TICK_RATE};
+use strum::IntoEnumIterator;
+
+// Longest step the engine takes in one go, 10 seconds of game time.
+// Health changes non-linearly, so it is kept reasonably small.
+pub const MAX_STEP_TICKS: u32 = 300;
+
+// How much a merchant's income may drift from the money it earns during a
+// step before a new step is needed, relative to the income
+const COMMISSION_TOLERANCE: f64 = 0.001;
+
+// Picks how many ticks the next step can cover without skipping over anything
+// that would change the gains: a level up, an unlock or purchase becoming
+// affordable, a life event roll or one wearing off, death or a replayed input.
+// Inside such a step every gain is constant, so multiplying it by the step
+// length gives the same result as running the ticks one by one.
+pub fn pick_step_ticks(game: &Game, upkeep: f64, max_ticks: u32) -> u32 {
+    // The first tick of a life runs before anything has been unlocked
+    if max_ticks <= 1 || game.state.life_stats.current_tick == 0 {
+        return 1;
+    }
+    // Income and happiness are read by the auto functions and XP gain one tick
+    // late, so after they change a single tick is needed to catch them up.
+    // Merchant income follows the money, so it drifts a little on every tick.
+    let income = game.intermediate_state.get_value(game.input.work.into());
+    let happiness = game.intermediate_state.get_multiplier(KeyValues::Happiness);
+    let income_changed = if game.world.get_work(game.input.work).commission > 0.0 {
+        (game.state.items.income - income).abs() > COMMISSION_TOLERANCE * income.abs()
+    } else {
+        game.state.items.income != income
+    };
+    if income_changed || game.state.life_stats.happiness != happiness {
+        return 1;
+    }
+    let mut limit = max_ticks.min(MAX_STEP_TICKS) as f64;
+    let mut cap = |ticks: f64| {
+        if ticks.is_finite() {
+            limit = limit.min(ticks.max(1.0));
+        }
+    };
+
+    cap(ticks_to_work_level(game));
+    for stat_type in StatTypes::iter() {
+        let stat = &game.state.stats[stat_type as usize];
+        cap(ticks_to_exceed(
+            stat.next_level_progress,
+            calculate_stat_next_level_xp_needed(stat),
+            stat_xp_rate(game, stat_type),
+        ));
+    }
+    for skill_type in SkillTypes::iter() {
+        let skill = &game.state.skills[skill_type as usize];
+        let world_skill = &game.world.skills[skill_type as usize];
+        cap(ticks_to_exceed(
+            skill.next_level_progress,
+            calculate_skill_next_level_xp_needed(skill, world_skill),
+            skill_xp_rate(game, skill_type),
+        ));
+    }
+    cap(ticks_to_death(game));
+
+    let money = game.state.items.money;
+    let money_rate = income - upkeep;
+    cap(ticks_to_commission_drift(game, income, money_rate));
+    for threshold in money_thresholds(game) {
+        cap(ticks_to_cross(money, threshold, money_rate));
+    }
+    let divine_favor = game.state.items.divine_favor;
+    let divine_favor_rate = game.intermediate_state.get_value(KeyValues::DivineFavor);
+    for blessing in game.state.blessings.iter() {
+        cap(ticks_to_cross(
+            divine_favor,
+            blessing.next_level_cost,
+            divine_favor_rate,
+        ));
+    }
+
+    let current_tick = game.state.life_stats.current_tick;
+    cap((LIFE_EVENT_INTERVAL - current_tick % LIFE_EVENT_INTERVAL) as f64);
+    for life_event in game.state.life_events.iter() {
+        if life_event.is_active {
+            cap((life_event.active_until - current_tick) as f64);
+        }
+    }
+
+    if game.state.life_stats.replaying {
+        if let Some((tick, _)) = game
+            .previous_inputs
+            .mapping
+            .range(current_tick + 1..)
+            .next()
+        {
+            cap((tick - current_tick) as f64);
+        }
+    }
+    limit as u32
+}
+
+fn ticks_to_work_level(game: &Game) -> f64 {
+    let work = &game.state.works[game.input.work as usize];
+    let work_world = game.world.get_work(game.input.work);
+    ticks_to_exceed(
+        work.next_level_progress,
+        calculate_work_next_level_xp_needed(work, work_world),
+        work_xp_rate(game),
+    )
+}
+
+// Ticks until the commission on the money earned in the meantime would change
+// the income by more than COMMISSION_TOLERANCE
+fn ticks_to_commission_drift(game: &Game, income: f64, money_rate: f64) -> f64 {
+    let commission = game.world.get_work(game.input.work).commission;
+    // Income change per tick
+    let drift = commission
+        * game
+            .intermediate_state
+            .get_multiplier(game.input.work.into())
+        * money_rate.abs()
+        / TICK_RATE;
+    if drift <= 0.0 {
+        return f64::INFINITY;
+    }
+    (COMMISSION_TOLERANCE * income.abs() / drift).floor()
+}
+
+// Ticks until progress is strictly above needed, the level up condition
+pub(super) fn ticks_to_exceed(progress: f64, needed: f64, rate: f64) -> f64 {
+    let per_tick = rate / TICK_RATE;
+    if per_tick <= 0.0 {
+        return f64::INFINITY;
+    }
+    ((needed - progress) / per_tick).floor() + 1.0
+}
+
+// Ticks until value moves to the other side of threshold at rate per second
+pub(super) fn ticks_to_cross(value: f64, threshold: f64, rate: f64) -> f64 {
+    let per_tick = rate / TICK_RATE;
+    if per_tick > 0.0 && threshold > value {
+        ((threshold - value) / per_tick).ceil()
+    } else if per_tick < 0.0 && threshold <= value {
+        ((value - threshold) / -per_tick).floor() + 1.0
+    } else {
+        f64::INFINITY
+    }
+}
+
+fn ticks_to_death(game: &Game) -> f64 {
+    let life_stats = &game.state.life_stats;
+    let margin = life_stats.lifespan - life_stats.age - life_stats.health;
+    // age + health grows by the days, lifespan by the health gain
+    let health_per_tick = life_stats.health_rate / TICK_RATE;
+    let closing_per_tick = days_per_tick(game) - health_per_tick * (BASE_LIFESPAN - 1.0);
+    if closing_per_tick <= 0.0 {
+        return f64::INFINITY;
+    }
+    (margin / closing_per_tick).ceil()
+}
+
+// Money amounts where something gets unlocked, becomes visible or affordable
+fn money_thresholds(game: &Game) -> Vec<f64> {
+    let tier = game.state.rebirth_stats.tier;
+    let mut thresholds = vec![];
+    for item in game.world.boost_items.iter() {
+        if item.required_tier <= tier && !game.state.boost_items[item.name as usize].is_purchased {
+            thresholds.push(item.purchasing_cost);
+            thresholds.push(item.purchasing_cost / 20.0);
+        }
+    }
+    for tomb in game.world.tombs.iter() {
+        if tomb.required_tier <= tier && !game.state.tombs[tomb.name as usize].is_purchased {
+            thresholds.push(tomb.purchasing_cost);
+        }
+    }
+    for housing_type in HousingTypes::iter() {
+        let housing = game.world.get_housing(housing_type);
+        thresholds.push(housing.upkeep);
+        thresholds.push(housing.required_money);
+        thresholds.push(housing.required_money / 4.0);
+    }
+    thresholds
+}
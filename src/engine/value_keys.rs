@@ -1,15 +1,21 @@
 use crate::input::stat::StatTypes;
+use crate::input::work::WorkCategoryTypes;
 use crate::input::work::WorkTypes;
-use crate::input::{skill::SkillTypes, work::WorkCategoryTypes};
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
+use variant_count::VariantCount;
 
-#[derive(Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(
+    Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, Eq, Hash, VariantCount,
+)]
 pub enum KeyValues {
     //Other
     Happiness,
     Money,
     Coins,
+    Karma,
+    // How fast the character ages
+    TimeFactor,
     DivineFavor,
     Health,
     Skills,
@@ -19,6 +25,7 @@ pub enum KeyValues {
     SoldierXp,
     IntellectualXp,
     PriestXp,
+    MerchantXp,
     //Stats
     Str,
     Int,
@@ -55,22 +62,27 @@ pub enum KeyValues {
     FootCompanion,
     Hypaspist,
     LightCavalry,
-    //
-    // Trader,
-    // Merchant,
+    ThessalianCavalry,
+    CompanionCavalry,
+    SquadCommander,
+    Commander,
+    General,
+    //Intellectual
+    Servant,
+    Teacher,
+    Scribe,
+    Librarian,
+    Scholar,
+    Philosopher,
+    //Merchant
+    Trader,
+    Merchant,
     // Priest/Faith
     Priest,
     Bishop,
 }
 
-impl From<SkillTypes> for KeyValues {
-    fn from(stat: SkillTypes) -> Self {
-        match stat {
-            SkillTypes::Mindful => KeyValues::Mindful,
-            SkillTypes::Tactics => KeyValues::Tactics,
-        }
-    }
-}
+pub const KEY_VALUES_SIZE: usize = KeyValues::VARIANT_COUNT;
 
 impl From<StatTypes> for KeyValues {
     fn from(stat: StatTypes) -> Self {
@@ -92,6 +104,7 @@ impl From<WorkCategoryTypes> for KeyValues {
             WorkCategoryTypes::Soldier => KeyValues::SoldierXp,
             WorkCategoryTypes::Intellectual => KeyValues::IntellectualXp,
             WorkCategoryTypes::Priest => KeyValues::PriestXp,
+            WorkCategoryTypes::Merchant => KeyValues::MerchantXp,
         }
     }
 }
@@ -116,7 +129,8 @@ impl From<WorkTypes> for KeyValues {
             WorkTypes::Goldsmith => KeyValues::Goldsmith,
             WorkTypes::GlassBlower => KeyValues::GlassBlower,
             WorkTypes::Weaponsmith => KeyValues::Weaponsmith,
-            // WorkTypes::Trader => KeyValues::Trader,
+            WorkTypes::Trader => KeyValues::Trader,
+            WorkTypes::Merchant => KeyValues::Merchant,
             WorkTypes::BaggageBoy => KeyValues::BaggageBoy,
             WorkTypes::Slinger => KeyValues::Slinger,
             WorkTypes::Peltast => KeyValues::Peltast,
@@ -124,6 +138,17 @@ impl From<WorkTypes> for KeyValues {
             WorkTypes::FootCompanion => KeyValues::FootCompanion,
             WorkTypes::Hypaspist => KeyValues::Hypaspist,
             WorkTypes::LightCavalry => KeyValues::LightCavalry,
+            WorkTypes::ThessalianCavalry => KeyValues::ThessalianCavalry,
+            WorkTypes::CompanionCavalry => KeyValues::CompanionCavalry,
+            WorkTypes::SquadCommander => KeyValues::SquadCommander,
+            WorkTypes::Commander => KeyValues::Commander,
+            WorkTypes::General => KeyValues::General,
+            WorkTypes::Servant => KeyValues::Servant,
+            WorkTypes::Teacher => KeyValues::Teacher,
+            WorkTypes::Scribe => KeyValues::Scribe,
+            WorkTypes::Librarian => KeyValues::Librarian,
+            WorkTypes::Scholar => KeyValues::Scholar,
+            WorkTypes::Philosopher => KeyValues::Philosopher,
             WorkTypes::Priest => KeyValues::Priest,
             WorkTypes::Bishop => KeyValues::Bishop,
         }
@@ -0,0 +1,130 @@
+use crate::input::achievement::AchievementTypes;
+use crate::input::activity::ActivityTypes;
+use crate::input::blessing::BlessingTypes;
+use crate::input::boost_item::BoostItemTypes;
+use crate::input::challenge::ChallengeTypes;
+use crate::input::deity::DeityTypes;
+use crate::input::housing::HousingTypes;
+use crate::input::karma_upgrade::KarmaUpgradeTypes;
+use crate::input::life_event::LifeEventTypes;
+use crate::input::rebirth_upgrade::RebirthUpgradeTypes;
+use crate::input::skill::SkillTypes;
+use crate::input::stat::StatTypes;
+use crate::input::tomb::TombTypes;
+use crate::input::work::WorkTypes;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+// Oldest events are dropped when nobody drains the queue, e.g. in the simulator
+const MAX_EVENTS: usize = 1000;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum GameEventType {
+    // Couldn't pay the upkeep of the chosen housing, living on the StoneFloor
+    Evicted {
+        housing: HousingTypes,
+    },
+    WorkUnlocked {
+        work: WorkTypes,
+    },
+    ActivityUnlocked {
+        activity: ActivityTypes,
+    },
+    HousingUnlocked {
+        housing: HousingTypes,
+    },
+    WorkLevelUp {
+        work: WorkTypes,
+        level: u32,
+    },
+    StatLevelUp {
+        stat: StatTypes,
+        level: f64,
+    },
+    SkillLevelUp {
+        skill: SkillTypes,
+        level: f64,
+    },
+    ItemBought {
+        item: BoostItemTypes,
+        auto: bool,
+    },
+    TombBought {
+        tomb: TombTypes,
+        auto: bool,
+    },
+    BlessingBought {
+        blessing: BlessingTypes,
+        level: u32,
+        auto: bool,
+    },
+    RebirthUpgradeBought {
+        upgrade: RebirthUpgradeTypes,
+    },
+    KarmaUpgradeBought {
+        upgrade: KarmaUpgradeTypes,
+    },
+    TierBought {
+        tier: u32,
+    },
+    // Rolled from the seed of the life, see world_content/life_event.rs
+    LifeEventHappened {
+        life_event: LifeEventTypes,
+    },
+    LifeEnded {
+        age: f64,
+        coins_gain: f64,
+        karma_gain: f64,
+    },
+    Rebirth {
+        rebirth_count: u32,
+    },
+    AchievementUnlocked {
+        achievement: AchievementTypes,
+    },
+    ChallengeCompleted {
+        challenge: ChallengeTypes,
+    },
+    DeityChosen {
+        deity: DeityTypes,
+    },
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct GameEvent {
+    pub tick: u32,
+    pub event: GameEventType,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EventQueue {
+    events: VecDeque<GameEvent>,
+}
+
+impl EventQueue {
+    pub fn new() -> EventQueue {
+        EventQueue {
+            events: VecDeque::new(),
+        }
+    }
+
+    pub fn push(&mut self, tick: u32, event: GameEventType) {
+        if self.events.len() >= MAX_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(GameEvent { tick, event });
+    }
+
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        self.events.drain(..).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
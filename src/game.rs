@@ -1,7 +1,10 @@
+use crate::clock::{Clock, SystemClock};
 use crate::engine::intermediate_state::IntermediateState;
+use crate::events::{EventQueue, GameEventType};
 use crate::input::{Input, Recordable};
 use crate::input_recording::Inputs;
 use crate::meta::MetaData;
+use crate::sampler::Sampler;
 use crate::state::state_container::StateContainer;
 use crate::world_content::world::World;
 use crate::WORLD;
@@ -16,6 +19,11 @@ pub struct Game {
     pub inputs: Inputs,
     pub previous_inputs: Inputs,
     pub just_loaded: bool,
+    pub clock: Box<dyn Clock>,
+    // The state as of the last delta sent to the frontend
+    pub delta_state: Option<StateContainer>,
+    pub events: EventQueue,
+    pub sampler: Sampler,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -65,11 +73,15 @@ impl Default for Game {
 
 impl Game {
     pub fn new() -> Game {
+        Game::with_clock(SystemClock)
+    }
+
+    pub fn with_clock<C: Clock + 'static>(clock: C) -> Game {
         let world = &WORLD;
         let state = StateContainer::default();
         let input = Input::new(&state);
         let intermediate_state = IntermediateState::new();
-        let meta_data = MetaData::new();
+        let meta_data = MetaData::new(clock.now());
         let inputs = Inputs::default();
         let previous_inputs = Inputs::default();
         Game {
@@ -81,9 +93,23 @@ impl Game {
             inputs,
             previous_inputs,
             just_loaded: false,
+            clock: Box::new(clock),
+            delta_state: None,
+            events: EventQueue::new(),
+            sampler: Sampler::default(),
         }
     }
 
+    pub fn set_clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.meta_data.set_save_time(clock.now());
+        self.meta_data.last_tick_time = clock.now();
+        self.clock = Box::new(clock);
+    }
+
+    pub fn push_event(&mut self, event: GameEventType) {
+        self.events.push(self.state.life_stats.current_tick, event);
+    }
+
     pub fn register_input<T: Recordable>(&mut self, key: T) {
         let tick = self.state.life_stats.current_tick;
         self.inputs.register_input_on_tick(tick, key);
@@ -112,9 +138,12 @@ impl Game {
         self.state = StateContainer::default();
         self.input = Input::new(&self.state);
         self.intermediate_state = IntermediateState::new();
-        self.meta_data = MetaData::new();
+        self.meta_data = MetaData::new(self.clock.now());
         self.inputs = Inputs::default();
         self.previous_inputs = Inputs::default();
+        self.delta_state = None;
+        self.sampler.clear();
+        self.events = EventQueue::new();
     }
 
     pub fn load_game(&mut self, save: GameSave) {
@@ -130,6 +159,9 @@ impl Game {
         self.meta_data = meta_data;
         self.inputs = inputs;
         self.previous_inputs = previous_inputs;
+        self.intermediate_state.invalidate();
+        self.delta_state = None;
+        self.sampler.clear();
         self.just_loaded = true;
     }
 }
@@ -0,0 +1,18 @@
+extern crate variant_count;
+
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+use variant_count::VariantCount;
+
+#[derive(
+    Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, PartialOrd, VariantCount,
+)]
+pub enum AchievementTypes {
+    Journeyman,
+    Master,
+    Undertaker,
+    RapidAscent,
+    DiedYoung,
+}
+
+pub const ACHIEVEMENT_SIZE: usize = AchievementTypes::VARIANT_COUNT;
@@ -24,8 +24,23 @@ pub enum ActivityTypes {
 
 pub const ACTIVITY_SIZE: usize = ActivityTypes::VARIANT_COUNT;
 
+// Weights are whole percentages, so every one of them can be replayed
+pub const MAX_ACTIVITY_WEIGHT: u32 = 100;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ActivityWeight {
+    pub activity: ActivityTypes,
+    pub weight: u32,
+}
+
 impl Recordable for ActivityTypes {
     fn to_record_key(&self) -> String {
         format!("Set Activity {:#?}", self)
     }
 }
+
+impl Recordable for ActivityWeight {
+    fn to_record_key(&self) -> String {
+        format!("Set Activity Weight {:#?} {}", self.activity, self.weight)
+    }
+}
@@ -14,6 +14,8 @@ pub enum BlessingTypes {
     AthenasWisdom,
     AfroditesCharm,
     PoseidonsSturdiness,
+    HermesSwiftness,
+    HadesBargain,
 }
 
 pub const BLESSING_SIZE: usize = BlessingTypes::VARIANT_COUNT;
@@ -61,6 +61,12 @@ pub enum BoostItemTypes {
     Book6,
     Shoe6,
     Clothes6,
+    Quill,
+    Abacus,
+    Scrolls,
+    JugglingBalls,
+    FineTools,
+    BalanceBeam,
 }
 
 pub const BOOST_ITEM_SIZE: usize = BoostItemTypes::VARIANT_COUNT;
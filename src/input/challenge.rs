@@ -0,0 +1,18 @@
+extern crate variant_count;
+
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+use variant_count::VariantCount;
+
+#[derive(
+    Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, PartialOrd, VariantCount,
+)]
+pub enum ChallengeTypes {
+    // Only Labor works
+    LaborOnly,
+    // No housing upgrades, the whole life on the StoneFloor
+    StoneFloor,
+    NoBoostItems,
+}
+
+pub const CHALLENGE_SIZE: usize = ChallengeTypes::VARIANT_COUNT;
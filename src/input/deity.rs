@@ -0,0 +1,27 @@
+extern crate variant_count;
+
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+use variant_count::VariantCount;
+
+use super::Recordable;
+
+#[derive(
+    Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, PartialOrd, VariantCount,
+)]
+pub enum DeityTypes {
+    Herucles,
+    Athena,
+    Afrodite,
+    Poseidon,
+    Hermes,
+    Hades,
+}
+
+pub const DEITY_SIZE: usize = DeityTypes::VARIANT_COUNT;
+
+impl Recordable for DeityTypes {
+    fn to_record_key(&self) -> String {
+        format!("Choose Deity {:#?}", self)
+    }
+}
@@ -0,0 +1,20 @@
+extern crate variant_count;
+
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+use variant_count::VariantCount;
+
+#[derive(
+    Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, PartialOrd, VariantCount,
+)]
+pub enum KarmaUpgradeTypes {
+    InnerPeace,
+    Devotion,
+    Pilgrimage,
+    GoodDeeds,
+    Virtue,
+    Enlightenment,
+    Serenity,
+}
+
+pub const KARMA_UPGRADE_SIZE: usize = KarmaUpgradeTypes::VARIANT_COUNT;
@@ -0,0 +1,17 @@
+extern crate variant_count;
+
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+use variant_count::VariantCount;
+
+#[derive(
+    Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, PartialOrd, VariantCount,
+)]
+pub enum LifeEventTypes {
+    Windfall,
+    Robbery,
+    Illness,
+    Festival,
+}
+
+pub const LIFE_EVENT_SIZE: usize = LifeEventTypes::VARIANT_COUNT;
@@ -1,7 +1,12 @@
+pub mod achievement;
 pub mod activity;
 pub mod blessing;
 pub mod boost_item;
+pub mod challenge;
+pub mod deity;
 pub mod housing;
+pub mod karma_upgrade;
+pub mod life_event;
 pub mod options;
 pub mod rebirth_upgrade;
 pub mod skill;
@@ -13,11 +18,13 @@ use crate::{
     world_content::boost_item::{translate_boost_item, BoostItem},
     WORLD,
 };
-use activity::ActivityTypes;
+use activity::{ActivityTypes, ACTIVITY_SIZE};
+use deity::DeityTypes;
 use housing::HousingTypes;
 use work::WorkTypes;
 
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
 use crate::state::state_container::StateContainer;
 
@@ -28,7 +35,14 @@ pub struct Input {
     pub work: WorkTypes,
     pub housing: HousingTypes,
     pub activity: ActivityTypes,
+    // Time split between activities, indexed by ActivityTypes. All zero means
+    // all time goes to `activity`.
+    #[serde(default)]
+    pub activity_weights: [u32; ACTIVITY_SIZE],
     pub item_queue: Vec<BoostItemTypes>,
+    // The patron of this life, chosen before the first blessing is bought
+    #[serde(default)]
+    pub deity: Option<DeityTypes>,
 }
 
 impl Input {
@@ -37,10 +51,27 @@ impl Input {
             work: WORLD.tiers[state.rebirth_stats.tier as usize].starting_work,
             housing: HousingTypes::StoneFloor,
             activity: ActivityTypes::Run,
+            activity_weights: [0; ACTIVITY_SIZE],
             item_queue: vec![],
+            deity: None,
         }
     }
 
+    // The share of time spent on each activity, adding up to 1
+    pub fn activity_shares(&self) -> Vec<(ActivityTypes, f64)> {
+        let total: u32 = self.activity_weights.iter().sum();
+        if total == 0 {
+            return vec![(self.activity, 1.0)];
+        }
+        ActivityTypes::iter()
+            .filter(|activity| self.activity_weights[*activity as usize] > 0)
+            .map(|activity| {
+                let weight = self.activity_weights[activity as usize];
+                (activity, weight as f64 / total as f64)
+            })
+            .collect()
+    }
+
     pub fn dequeue_item(&mut self, item: BoostItemTypes) {
         self.item_queue.retain(|item_type| *item_type != item);
     }
@@ -44,6 +44,15 @@ pub enum RebirthUpgradeTypes {
     BribeCharon2,
     BribeCharon3,
     GemKnowledge,
+    BorrowedTime1,
+    BorrowedTime2,
+    BorrowedTime3,
+    IntellectualXp1,
+    Privilege4,
+    BribeCharon4,
+    SoldierXp4,
+    IntellectualXp2,
+    BorrowedTime4,
 }
 
 pub const REBIRTH_UPGRADE_SIZE: usize = RebirthUpgradeTypes::VARIANT_COUNT;
@@ -10,6 +10,9 @@ use variant_count::VariantCount;
 pub enum SkillTypes {
     Mindful,
     Tactics,
+    Oratory,
+    Craftsmanship,
+    Theology,
 }
 
 pub const SKILL_SIZE: usize = SkillTypes::VARIANT_COUNT;
@@ -24,6 +24,7 @@ impl From<WorkCategoryTypes> for StatTypes {
             WorkCategoryTypes::Labor => StatTypes::Con,
             WorkCategoryTypes::Intellectual => StatTypes::Int,
             WorkCategoryTypes::Priest => StatTypes::Faith,
+            WorkCategoryTypes::Merchant => StatTypes::Cha,
         }
     }
 }
@@ -18,6 +18,13 @@ pub enum TombTypes {
     Crypt,
     Mausuleum,
     Catacomb,
+    Barrow,
+    Sepulchre,
+    Tumulus,
+    Tholos,
+    RoyalTomb,
+    Necropolis,
+    Pyramid,
 }
 
 pub const TOMB_SIZE: usize = TombTypes::VARIANT_COUNT;
@@ -27,8 +27,6 @@ pub enum WorkTypes {
     Goldsmith,
     GlassBlower,
     Weaponsmith,
-    // Trader,
-    // Merchant,
     // Army Types
     BaggageBoy,
     Slinger,
@@ -37,17 +35,25 @@ pub enum WorkTypes {
     FootCompanion,
     Hypaspist,
     LightCavalry,
-    // ThessalianCavalry,
-    // CompanionCavalry,
-    // SquadCommander,
-    // Commander,
-    // General,
-    // Intellectual Types
-    // Servant,
-    // Teacher,
     // Priest/Faith
     Priest,
     Bishop,
+    // Intellectual Types
+    Servant,
+    Teacher,
+    Scribe,
+    Librarian,
+    Scholar,
+    Philosopher,
+    // Merchant Types
+    Trader,
+    Merchant,
+    // Upper Army Types
+    ThessalianCavalry,
+    CompanionCavalry,
+    SquadCommander,
+    Commander,
+    General,
 }
 
 #[derive(Serialize, Deserialize, EnumIter, Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
@@ -56,6 +62,7 @@ pub enum WorkCategoryTypes {
     Soldier,
     Intellectual,
     Priest,
+    Merchant,
 }
 
 impl TryFrom<StatTypes> for WorkCategoryTypes {
@@ -66,6 +73,7 @@ impl TryFrom<StatTypes> for WorkCategoryTypes {
             StatTypes::Con => Ok(WorkCategoryTypes::Labor),
             StatTypes::Int => Ok(WorkCategoryTypes::Intellectual),
             StatTypes::Faith => Ok(WorkCategoryTypes::Priest),
+            StatTypes::Cha => Ok(WorkCategoryTypes::Merchant),
             _ => Err(()),
         }
     }
@@ -4,9 +4,10 @@ use std::collections::HashMap;
 use strum::IntoEnumIterator;
 
 use crate::game::Game;
-use crate::input::activity::ActivityTypes;
+use crate::input::activity::{ActivityTypes, ActivityWeight, MAX_ACTIVITY_WEIGHT};
 use crate::input::blessing::BlessingTypes;
 use crate::input::boost_item::BoostItemTypes;
+use crate::input::deity::DeityTypes;
 use crate::input::housing::HousingTypes;
 use crate::input::options::AutoSettingTypes;
 use crate::input::tomb::TombTypes;
@@ -17,8 +18,8 @@ use crate::wasm_api::auto_settings::{
     set_auto_living_internal, set_auto_rebirth_internal, set_auto_work_internal,
 };
 use crate::{
-    buy_blessing_internal, buy_item_internal, buy_tomb_internal, set_activity_internal,
-    set_housing_internal, set_work_internal,
+    buy_blessing_internal, buy_item_internal, buy_tomb_internal, choose_deity_internal,
+    set_activity_internal, set_activity_weight_internal, set_housing_internal, set_work_internal,
 };
 
 type Callback = Box<dyn Fn(&mut Game) + Send>;
@@ -122,6 +123,14 @@ impl Default for InputMapping {
                 }),
             );
         }
+        for deity in DeityTypes::iter() {
+            mapping.add(
+                deity,
+                Box::new(move |game: &mut Game| {
+                    choose_deity_internal(deity, game);
+                }),
+            );
+        }
         for tomb in TombTypes::iter() {
             mapping.add(
                 tomb,
@@ -161,6 +170,14 @@ impl Default for InputMapping {
                     set_activity_internal(activity, game);
                 }),
             );
+            for weight in 0..=MAX_ACTIVITY_WEIGHT {
+                mapping.add(
+                    ActivityWeight { activity, weight },
+                    Box::new(move |game: &mut Game| {
+                        set_activity_weight_internal(activity, weight, game);
+                    }),
+                );
+            }
         }
         mapping
     }
@@ -3,6 +3,7 @@
 use icon::{Icon, IconType};
 use input_recording::{Inputs, RecordedInputEntry};
 use log::{info, Level};
+use std::collections::VecDeque;
 use std::sync::Mutex;
 use wasm_bindgen::prelude::*;
 
@@ -15,7 +16,9 @@ extern crate lazy_static;
 // #[macro_use]
 // extern crate serde_big_array;
 
+pub mod clock;
 pub mod engine;
+pub mod events;
 pub mod game;
 pub mod icon;
 pub mod info;
@@ -24,6 +27,7 @@ pub mod input_mapping;
 pub mod input_recording;
 pub mod meta;
 pub mod presets;
+pub mod sampler;
 pub mod state;
 pub mod util;
 pub mod wasm_api;
@@ -32,26 +36,44 @@ pub mod world_content;
 use crate::info::check_for_tutorial_step;
 use crate::input::blessing::BlessingTypes;
 use engine::auto_functions::register_auto_settings;
-use engine::{character_death_update, engine_run, update_unlocks};
+use engine::intermediate_state::ValueBreakdown;
+use engine::projection::{predicted_death_age, project, Projection, ProjectionTarget};
+use engine::value_keys::KeyValues;
+use engine::{character_death_update, engine_run, engine_step, update_unlocks};
+use events::GameEventType;
 use game::Game;
-use input::activity::ActivityTypes;
+use input::activity::{ActivityTypes, ActivityWeight, ACTIVITY_SIZE, MAX_ACTIVITY_WEIGHT};
 use input::boost_item::BoostItemTypes;
+use input::challenge::ChallengeTypes;
+use input::deity::DeityTypes;
 use input::housing::HousingTypes;
+use input::karma_upgrade::KarmaUpgradeTypes;
 use input::rebirth_upgrade::RebirthUpgradeTypes;
 use input::tomb::TombTypes;
 use input::work::WorkTypes;
 use input::Input;
+use state::delta::StateDelta;
+use state::life_history::LifeSummary;
+use state::rng::{new_seed, Rng};
 use state::state_container::rebirth;
 use wasm_api::meta::do_save;
+use world_content::achievement::{get_achievement_progress, AchievementProgress};
 use world_content::boost_item::BoostItem;
+use world_content::challenge::{
+    can_start_challenge, challenge_allows_boost_items, challenge_allows_housing,
+    challenge_allows_work,
+};
+use world_content::deity::{can_choose_deity, deity_allows_blessing};
+use world_content::karma_upgrade::should_unlock_karma_upgrade;
 use world_content::rebirth_upgrade::RebirthUpgrade;
 use world_content::tier::Tier;
 use world_content::tomb::Tomb;
 use world_content::world::World;
 
 const BASE_LIFESPAN: f64 = 70.0 * 365.0;
-const TICK_RATE: f64 = 30.0;
-const TICK_MS: f64 = 1000.0 / TICK_RATE;
+pub const TICK_RATE: f64 = 30.0;
+pub const TICK_MS: f64 = 1000.0 / TICK_RATE;
+const AUTO_END_EARLY_MIN_TICK: u32 = 5000;
 
 lazy_static! {
     static ref WORLD: World = World::default();
@@ -132,6 +154,96 @@ pub fn get_state() -> JsValue {
     serde_wasm_bindgen::to_value(&game.state).unwrap()
 }
 
+// Only what changed since the previous call, see StateDelta
+#[wasm_bindgen]
+pub fn get_state_delta() -> JsValue {
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(&get_state_delta_internal(game)).unwrap()
+}
+
+pub fn get_state_delta_internal(game: &mut Game) -> StateDelta {
+    let delta = match &game.delta_state {
+        Some(old_state) => StateDelta::diff(old_state, &game.state),
+        None => StateDelta::full(&game.state),
+    };
+    if !delta.is_empty() {
+        game.delta_state = Some(game.state.clone());
+    }
+    delta
+}
+
+// The bases and multipliers, with their sources, that make up a value
+#[wasm_bindgen]
+pub fn get_value_breakdown(val: &JsValue) -> JsValue {
+    let key: KeyValues = serde_wasm_bindgen::from_value(val.clone()).unwrap();
+    let game = GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(&get_value_breakdown_internal(key, &game)).unwrap()
+}
+
+pub fn get_value_breakdown_internal(key: KeyValues, game: &Game) -> ValueBreakdown {
+    game.intermediate_state.get_breakdown(key)
+}
+
+// Everything that happened since the previous call, oldest first
+#[wasm_bindgen]
+pub fn drain_events() -> JsValue {
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(&game.events.drain()).unwrap()
+}
+
+// Estimated time until the next level or until something can be afforded
+#[wasm_bindgen]
+pub fn get_projection(val: &JsValue) -> JsValue {
+    let target: ProjectionTarget = serde_wasm_bindgen::from_value(val.clone()).unwrap();
+    let game = GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(&get_projection_internal(target, &game)).unwrap()
+}
+
+pub fn get_projection_internal(target: ProjectionTarget, game: &Game) -> Option<Projection> {
+    project(game, target)
+}
+
+#[wasm_bindgen]
+pub fn get_predicted_death_age() -> f64 {
+    let game = GLOBAL_DATA.lock().unwrap();
+    predicted_death_age(&game)
+}
+
+// Money, income, health, happiness and coins over the current life
+#[wasm_bindgen]
+pub fn get_samples() -> JsValue {
+    let game = GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(game.sampler.samples()).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn set_sample_interval(ticks: u32) {
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    game.sampler.set_interval(ticks);
+}
+
+// Summaries of past lives, oldest first
+#[wasm_bindgen]
+pub fn get_life_history() -> JsValue {
+    let game = GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(get_life_history_internal(&game)).unwrap()
+}
+
+pub fn get_life_history_internal(game: &Game) -> &VecDeque<LifeSummary> {
+    game.state.rebirth_stats.history.lives()
+}
+
+// Every achievement with how far along it is, unlocked ones stay unlocked
+#[wasm_bindgen]
+pub fn get_achievements() -> JsValue {
+    let game = GLOBAL_DATA.lock().unwrap();
+    serde_wasm_bindgen::to_value(&get_achievements_internal(&game)).unwrap()
+}
+
+pub fn get_achievements_internal(game: &Game) -> Vec<AchievementProgress> {
+    get_achievement_progress(game)
+}
+
 #[wasm_bindgen]
 pub fn get_meta_data() -> JsValue {
     let game = GLOBAL_DATA.lock().unwrap();
@@ -162,12 +274,30 @@ pub fn do_rebirth() {
 }
 
 pub fn do_rebirth_internal(game: &mut Game) {
+    let rebirth_count = game.state.rebirth_stats.rebirth_count + 1;
+    let seed = new_seed(game.clock.now(), rebirth_count);
+    start_new_life(game, seed, None, false);
+}
+
+fn start_new_life(game: &mut Game, seed: u32, challenge: Option<ChallengeTypes>, replaying: bool) {
+    // A new life keeps the patron if it can, so blessings, and auto-buying
+    // them, carry on. A replay gets it back from its recorded inputs instead.
+    let patron = if replaying { None } else { game.input.deity };
     game.state.rebirth_stats.rebirth_count += 1;
     game.state = rebirth(game.state.rebirth_stats.clone());
+    game.state.rng = Rng::new(seed);
+    game.state.life_stats.challenge = challenge;
+    game.state.life_stats.replaying = replaying;
+    game.push_event(GameEventType::Rebirth {
+        rebirth_count: game.state.rebirth_stats.rebirth_count,
+    });
     game.input = Input::new(&game.state);
     game.previous_inputs = game.inputs.clone();
     game.inputs = Inputs::default();
     register_auto_settings(game);
+    if let Some(deity) = patron {
+        choose_deity_internal(deity, game);
+    }
     engine_run(game);
 }
 
@@ -177,16 +307,49 @@ pub fn do_rebirth_replay() {
     if !game.state.life_stats.dead {
         return;
     }
-    do_rebirth_internal(game);
-    game.state.life_stats.replaying = true;
+    do_rebirth_replay_internal(game);
     info!("Rust did rebirth replay");
 }
 
+// The replayed life keeps the seed and the challenge of the life that just
+// ended, so it gets the same life events under the same rules
+pub fn do_rebirth_replay_internal(game: &mut Game) {
+    let seed = game.state.rng.seed;
+    let challenge = game.state.life_stats.challenge;
+    start_new_life(game, seed, challenge, true);
+}
+
+#[wasm_bindgen]
+pub fn start_challenge(val: &JsValue) {
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    if !game.state.life_stats.dead {
+        return;
+    }
+    let challenge: ChallengeTypes = serde_wasm_bindgen::from_value(val.clone()).unwrap();
+    start_challenge_internal(challenge, game);
+    info!("Rust started challenge");
+}
+
+// Rebirths into a life played under the rules of the challenge
+pub fn start_challenge_internal(challenge: ChallengeTypes, game: &mut Game) {
+    if !can_start_challenge(challenge, game) {
+        return;
+    }
+    let rebirth_count = game.state.rebirth_stats.rebirth_count + 1;
+    let seed = new_seed(game.clock.now(), rebirth_count);
+    start_new_life(game, seed, Some(challenge), false);
+}
+
 #[wasm_bindgen]
 pub fn paused() {
     let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    paused_internal(game);
+}
+
+pub fn paused_internal(game: &mut Game) {
     check_for_tutorial_step(game);
-    game.meta_data.update_tick_time();
+    let now = game.clock.now();
+    game.meta_data.update_tick_time(now);
     game.meta_data.skip_tick();
     game.meta_data.convert_missed_time_to_saved_ticks();
     if game.meta_data.options.auto_rebirth {
@@ -215,10 +378,11 @@ pub fn tick() {
 
 pub fn tick_internal(game: &mut Game) {
     check_for_tutorial_step(game);
-    if game.meta_data.should_autosave() {
+    let now = game.clock.now();
+    if game.meta_data.should_autosave(now) {
         do_save(game);
     }
-    game.meta_data.update_tick_time();
+    game.meta_data.update_tick_time(now);
     if game.just_loaded {
         game.just_loaded = false;
         if game.state.life_stats.is_dying {
@@ -243,7 +407,7 @@ pub fn handle_missing_time(game: &mut Game) {
     if game.meta_data.options.use_missed_ticks {
         for i in 0..(game.meta_data.missed_time_ticks() as u64) {
             if i > game.meta_data.options.max_missed_ticks as u64 {
-                game.meta_data.convert_missed_time_to_saved_ticks();
+                catch_up(game);
                 return;
             }
             if game.state.life_stats.is_dying {
@@ -268,10 +432,38 @@ pub fn one_tick(game: &mut Game) {
     }
 }
 
+// Simulates the rest of the missed time in coarse engine steps instead of
+// single ticks. Stops when the character is dying, the remaining missed time
+// is then handled once the next life has started.
+pub fn catch_up(game: &mut Game) {
+    let game_speed = game.meta_data.game_speed.max(1);
+    loop {
+        let missed_ticks = game.meta_data.missed_time_ticks();
+        if missed_ticks < 1.0 || game.state.life_stats.is_dying {
+            return;
+        }
+        let mut max_ticks = (missed_ticks * game_speed as f64) as u32;
+        let current_tick = game.state.life_stats.current_tick;
+        if game.meta_data.options.auto_end_early && current_tick < AUTO_END_EARLY_MIN_TICK {
+            max_ticks = max_ticks.min(AUTO_END_EARLY_MIN_TICK - current_tick);
+        }
+        let ticks = engine_step(game, max_ticks);
+        if ticks == 0 {
+            return;
+        }
+        game.meta_data.missed_time -= ticks as f64 / game_speed as f64 * TICK_MS;
+        if should_auto_end_early(game) {
+            info!("Auto ending early");
+            die_internal(game);
+            do_rebirth_internal(game);
+        }
+    }
+}
+
 fn should_auto_end_early(game: &Game) -> bool {
     let options = &game.meta_data.options;
     let enough_coins = options.auto_end_early_criteria < game.state.rebirth_stats.coins_gain;
-    let old_enough = game.state.life_stats.current_tick >= 5000;
+    let old_enough = game.state.life_stats.current_tick >= AUTO_END_EARLY_MIN_TICK;
     // info!(
     //     "Auto ending early {}, enough_coins {}, criteria {}, gain {}",
     //     options.auto_end_early,
@@ -291,6 +483,9 @@ pub fn set_work(val: &JsValue) {
 }
 
 pub fn set_work_internal(work_type: WorkTypes, game: &mut Game) {
+    if !challenge_allows_work(work_type, game) {
+        return;
+    }
     game.register_input(work_type);
     game.input.work = work_type;
 }
@@ -304,6 +499,9 @@ pub fn set_housing(val: &JsValue) {
 }
 
 pub fn set_housing_internal(housing_type: HousingTypes, game: &mut Game) {
+    if !challenge_allows_housing(housing_type, game) {
+        return;
+    }
     game.register_input(housing_type);
     game.input.housing = housing_type;
 }
@@ -319,12 +517,41 @@ pub fn set_activity(val: &JsValue) {
 pub fn set_activity_internal(activity_type: ActivityTypes, game: &mut Game) {
     game.register_input(activity_type);
     game.input.activity = activity_type;
+    game.input.activity_weights = [0; ACTIVITY_SIZE];
+}
+
+#[wasm_bindgen]
+pub fn set_activity_weight(val: &JsValue, weight: u32) {
+    info!("Rust set activity weight");
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    let activity_type = serde_wasm_bindgen::from_value(val.clone()).unwrap();
+    set_activity_weight_internal(activity_type, weight, game);
+}
+
+pub fn set_activity_weight_internal(activity_type: ActivityTypes, weight: u32, game: &mut Game) {
+    // Time can only be split across unlocked activities
+    if weight > 0 && !game.state.activities[activity_type as usize].is_unlocked {
+        return;
+    }
+    let weight = weight.min(MAX_ACTIVITY_WEIGHT);
+    game.register_input(ActivityWeight {
+        activity: activity_type,
+        weight,
+    });
+    game.input.activity_weights[activity_type as usize] = weight;
 }
 
 #[wasm_bindgen]
 pub fn can_buy_tier(val: u32) -> bool {
     let game = GLOBAL_DATA.lock().unwrap();
-    let tier: &Tier = &game.world.tiers[val as usize];
+    can_buy_tier_internal(val, &game)
+}
+
+pub fn can_buy_tier_internal(val: u32, game: &Game) -> bool {
+    let tier: &Tier = match game.world.tiers.get(val as usize) {
+        Some(tier) => tier,
+        None => return false,
+    };
     let next_tier: bool = game.state.rebirth_stats.tier + 1 == val;
     let can_afford: bool = game.state.rebirth_stats.coins >= tier.purchasing_cost;
     can_afford && next_tier
@@ -333,13 +560,18 @@ pub fn can_buy_tier(val: u32) -> bool {
 #[wasm_bindgen]
 pub fn buy_tier(val: u32) {
     info!("Rust buy tier");
-    if can_buy_tier(val) {
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    buy_tier_internal(val, game);
+}
+
+pub fn buy_tier_internal(val: u32, game: &mut Game) {
+    if can_buy_tier_internal(val, game) {
         info!("Can buy tier");
-        let mut game = GLOBAL_DATA.lock().unwrap();
         let tier: &Tier = &game.world.tiers[val as usize];
         game.state.rebirth_stats.coins -= tier.purchasing_cost;
         game.state.rebirth_stats.tier = val;
-        update_unlocks(&mut game);
+        game.push_event(GameEventType::TierBought { tier: val });
+        update_unlocks(game);
     }
 }
 
@@ -359,6 +591,10 @@ pub fn buy_tomb_internal(tomb_type: TombTypes, game: &mut Game) {
         let tomb: &Tomb = &game.world.tombs[tomb_type as usize];
         game.state.items.money -= tomb.purchasing_cost;
         game.state.tombs[tomb_type as usize].is_purchased = true;
+        game.push_event(GameEventType::TombBought {
+            tomb: tomb_type,
+            auto: false,
+        });
         update_unlocks(game);
     }
 }
@@ -385,13 +621,36 @@ pub fn buy_blessing_internal(blessing_type: BlessingTypes, game: &mut Game) {
         let blessing = &game.state.blessings[blessing_type as usize];
         game.state.items.divine_favor -= blessing.next_level_cost;
         game.state.blessings[blessing_type as usize].level += 1;
+        game.push_event(GameEventType::BlessingBought {
+            blessing: blessing_type,
+            level: game.state.blessings[blessing_type as usize].level,
+            auto: false,
+        });
     }
 }
 
 pub fn can_buy_blessing(blessing: BlessingTypes, game: &mut Game) -> bool {
-    let blessing = &game.state.blessings[blessing as usize];
-    let can_afford: bool = game.state.items.divine_favor >= blessing.next_level_cost;
-    can_afford
+    let blessing_state = &game.state.blessings[blessing as usize];
+    let can_afford: bool = game.state.items.divine_favor >= blessing_state.next_level_cost;
+    can_afford && deity_allows_blessing(blessing, game)
+}
+
+#[wasm_bindgen]
+pub fn choose_deity(val: &JsValue) {
+    info!("Rust choose deity");
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    let deity: DeityTypes = serde_wasm_bindgen::from_value(val.clone()).unwrap();
+    choose_deity_internal(deity, game);
+}
+
+pub fn choose_deity_internal(deity: DeityTypes, game: &mut Game) {
+    if !can_choose_deity(deity, game) {
+        return;
+    }
+    game.register_input(deity);
+    game.input.deity = Some(deity);
+    game.push_event(GameEventType::DeityChosen { deity });
+    update_unlocks(game);
 }
 
 #[wasm_bindgen]
@@ -425,6 +684,10 @@ pub fn buy_item_internal(boost_item_type: BoostItemTypes, game: &mut Game) {
         let item: &BoostItem = &game.world.boost_items[boost_item_type as usize];
         game.state.items.money -= item.purchasing_cost;
         game.state.boost_items[boost_item_type as usize].is_purchased = true;
+        game.push_event(GameEventType::ItemBought {
+            item: boost_item_type,
+            auto: false,
+        });
         update_unlocks(game);
     }
 }
@@ -433,7 +696,7 @@ pub fn can_buy_item(boost_item_type: BoostItemTypes, game: &mut Game) -> bool {
     let item = &game.world.boost_items[boost_item_type as usize];
     let item_state = &game.state.boost_items[boost_item_type as usize];
     let can_afford: bool = game.state.items.money >= item.purchasing_cost;
-    can_afford && !item_state.is_purchased
+    can_afford && !item_state.is_purchased && challenge_allows_boost_items(game)
 }
 
 #[wasm_bindgen]
@@ -449,10 +712,39 @@ pub fn buy_rebirth_upgrade(val: &JsValue) {
         game.state.rebirth_stats.coins -= rebirth_upgrade.purchasing_cost;
         game.state.rebirth_stats.rebirth_upgrades[rebirth_upgrade_type as usize].is_purchased =
             true;
+        game.push_event(GameEventType::RebirthUpgradeBought {
+            upgrade: rebirth_upgrade_type,
+        });
         update_unlocks(&mut game);
     }
 }
 
+#[wasm_bindgen]
+pub fn buy_karma_upgrade(val: &JsValue) {
+    info!("Rust buy karma upgrade");
+    let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
+    let karma_upgrade_type: KarmaUpgradeTypes =
+        serde_wasm_bindgen::from_value(val.clone()).unwrap();
+    buy_karma_upgrade_internal(karma_upgrade_type, game);
+}
+
+pub fn buy_karma_upgrade_internal(karma_upgrade_type: KarmaUpgradeTypes, game: &mut Game) {
+    if can_buy_karma_upgrade(karma_upgrade_type, game) {
+        let karma_upgrade = &game.world.karma_upgrades[karma_upgrade_type as usize];
+        game.state.rebirth_stats.karma -= karma_upgrade.purchasing_cost;
+        game.state.rebirth_stats.karma_upgrades[karma_upgrade_type as usize].is_purchased = true;
+        game.push_event(GameEventType::KarmaUpgradeBought {
+            upgrade: karma_upgrade_type,
+        });
+        update_unlocks(game);
+    }
+}
+
+pub fn can_buy_karma_upgrade(karma_upgrade_type: KarmaUpgradeTypes, game: &Game) -> bool {
+    let karma_upgrade_state = &game.state.rebirth_stats.karma_upgrades[karma_upgrade_type as usize];
+    should_unlock_karma_upgrade(karma_upgrade_type, game) && !karma_upgrade_state.is_purchased
+}
+
 #[wasm_bindgen]
 pub fn die() {
     let game: &mut Game = &mut GLOBAL_DATA.lock().unwrap();
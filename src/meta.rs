@@ -1,7 +1,7 @@
+use crate::clock::{Clock, SystemClock};
 use crate::info::Info;
 use crate::input::options::Options;
 use crate::TICK_MS;
-use js_sys::Date;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -19,17 +19,17 @@ pub struct MetaData {
 
 impl Default for MetaData {
     fn default() -> Self {
-        Self::new()
+        Self::new(SystemClock.now())
     }
 }
 
 impl MetaData {
-    pub fn new() -> MetaData {
+    pub fn new(now: f64) -> MetaData {
         MetaData {
             game_speed: get_game_speed_default(),
             autosave: get_autosave_default(),
-            last_save_time: Date::new_0().get_time(),
-            last_tick_time: Date::new_0().get_time(),
+            last_save_time: now,
+            last_tick_time: now,
             missed_time: 0.0,
             saved_ticks: 0.0,
             info: Info::new(),
@@ -38,8 +38,7 @@ impl MetaData {
         }
     }
 
-    pub fn should_autosave(&self) -> bool {
-        let now = Date::new_0().get_time();
+    pub fn should_autosave(&self, now: f64) -> bool {
         self.autosave && now >= self.last_save_time + (60.0 * 1000.0)
     }
 
@@ -59,12 +58,11 @@ impl MetaData {
         }
     }
 
-    pub fn set_save_time(&mut self) {
-        self.last_save_time = Date::new_0().get_time();
+    pub fn set_save_time(&mut self, now: f64) {
+        self.last_save_time = now;
     }
 
-    pub fn update_tick_time(&mut self) {
-        let now = Date::new_0().get_time();
+    pub fn update_tick_time(&mut self, now: f64) {
         self.missed_time += (now - self.last_tick_time) - TICK_MS;
         // log::info!("delta: {}", (now - self.last_tick_time));
         // log::info!(
@@ -97,7 +95,7 @@ impl MetaData {
         self.missed_time -= TICK_MS;
     }
 
-    pub fn paused_tick_time(&mut self) {
+    pub fn paused_tick_time(&mut self, now: f64) {
         self.saved_ticks += 1.0;
         /*  commenting out next two lines because the if-block is empty,
             and 'now' is unused after commenting out the empty if-block
@@ -105,7 +103,7 @@ impl MetaData {
         let now = Date::new_0().get_time();
         if now >= self.last_tick_time + 500.0 {}
         */
-        self.last_tick_time = Date::new_0().get_time();
+        self.last_tick_time = now;
     }
 }
 
@@ -8,9 +8,9 @@ use crate::input::work::WorkTypes;
 use crate::input::Input;
 use crate::state::state_container::rebirth;
 use crate::util::{
-    balance_activities, get_run_study_array, get_training_meditate_array, get_training_study_array,
-    get_training_study_wargames_array, get_upgrades_up_to_current_tier,
-    get_upgrades_up_to_tier_max_cost, set_full_auto, set_jobs_at_tier_to, set_lower_tier_jobs_to,
+    get_run_study_weights, get_training_meditate_weights, get_training_study_wargames_weights,
+    get_training_study_weights, get_upgrades_up_to_current_tier, get_upgrades_up_to_tier_max_cost,
+    set_full_auto, set_jobs_at_tier_to, set_lower_tier_jobs_to, weigh_activities,
 };
 use std::collections::BTreeMap;
 use strum::IntoEnumIterator;
@@ -24,6 +24,7 @@ pub fn get_presets() -> BTreeMap<&'static str, GameSave> {
     presets.insert("04: T4 expected", make_t4());
     presets.insert("05: T5 expected", make_t5());
     presets.insert("06: T5 Faith", make_t5_faith());
+    presets.insert("07: T5 Intellectual", make_t5_intellectual());
     // presets.insert("Test_0: first rebirth", first_rebirth());
     presets.insert("T0 Test_1: Re 2", rebirth_2());
     presets.insert("T1 Test_2: Re 3", rebirth_3());
@@ -60,11 +61,10 @@ fn make_current() -> GameSave {
     game_save
         .previous_inputs
         .register_input_on_tick(30000, AutoSettingTypes::AutoBuyItemFalse);
-    balance_activities(
+    weigh_activities(
         &mut game_save.previous_inputs,
         4000,
-        30_000,
-        &[ActivityTypes::Run, ActivityTypes::Studying],
+        &[(ActivityTypes::Run, 1), (ActivityTypes::Studying, 1)],
     );
     game_save
         .previous_inputs
@@ -199,6 +199,26 @@ fn make_t5_faith() -> GameSave {
     game_save
 }
 
+pub fn make_t5_intellectual() -> GameSave {
+    let mut game_save = GameSave::default();
+    let state = &mut game_save.state;
+    let r = &mut state.rebirth_stats;
+    r.tier = 5;
+    get_upgrades_up_to_current_tier(r);
+    r.coins = 10000.0;
+    r.rebirth_count = 32;
+
+    set_lower_tier_jobs_to(r, 80);
+    r.rebirth_upgrades[RebirthUpgradeTypes::IntellectualXp1 as usize].is_purchased = true;
+
+    game_save.state = rebirth(r.clone());
+    set_full_auto(&mut game_save.meta_data.options);
+
+    game_save.input = Input::new(&game_save.state);
+    game_save.input.work = WorkTypes::Servant;
+    game_save
+}
+
 pub fn rebirth_2() -> GameSave {
     let mut game_save = GameSave::default();
     let r = &mut game_save.state.rebirth_stats;
@@ -234,7 +254,7 @@ pub fn rebirth_3() -> GameSave {
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
     pi.register_input_on_tick(30000, AutoSettingTypes::AutoBuyItemFalse);
-    balance_activities(pi, 4000, 30_000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(30000, ActivityTypes::Flirt);
 
     game_save.input = Input::new(&game_save.state);
@@ -258,7 +278,7 @@ pub fn rebirth_10() -> GameSave {
     let save_up_switch = 40_000;
     let pi = &mut game_save.previous_inputs;
     pi.register_input_on_tick(save_up_switch, AutoSettingTypes::AutoBuyItemFalse);
-    balance_activities(pi, 4000, save_up_switch, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(save_up_switch, ActivityTypes::Run);
     pi.register_input_on_tick(save_up_switch, HousingTypes::SharedRoom);
     pi.register_input_on_tick(save_up_switch, AutoSettingTypes::AutoLivingFalse);
@@ -284,7 +304,7 @@ pub fn rebirth_12() -> GameSave {
     let save_up_switch = 40_000;
     let pi = &mut game_save.previous_inputs;
     pi.register_input_on_tick(save_up_switch, AutoSettingTypes::AutoBuyItemFalse);
-    balance_activities(pi, 4000, save_up_switch, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(save_up_switch, ActivityTypes::Run);
     pi.register_input_on_tick(save_up_switch, HousingTypes::SharedRoom);
     pi.register_input_on_tick(save_up_switch, AutoSettingTypes::AutoLivingFalse);
@@ -308,9 +328,9 @@ pub fn rebirth_15() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 10000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(20000, WorkTypes::BaggageBoy);
-    balance_activities(pi, 10000, 40000, &get_training_study_array());
+    weigh_activities(pi, 10000, &get_training_study_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Training);
 
     game_save.input = Input::new(&game_save.state);
@@ -338,9 +358,9 @@ pub fn rebirth_18() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 10000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(25000, WorkTypes::BaggageBoy);
-    balance_activities(pi, 10000, 40000, &get_training_study_array());
+    weigh_activities(pi, 10000, &get_training_study_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Training);
 
     game_save.input = Input::new(&game_save.state);
@@ -371,9 +391,9 @@ pub fn rebirth_22() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 7000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(15000, WorkTypes::BaggageBoy);
-    balance_activities(pi, 7000, 40000, &get_training_study_array());
+    weigh_activities(pi, 7000, &get_training_study_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Training);
     pi.register_input_on_tick(40000, ActivityTypes::Flirt);
     pi.register_input_on_tick(40000, HousingTypes::LargeCloset);
@@ -400,9 +420,9 @@ pub fn rebirth_23() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 7000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(15000, WorkTypes::BaggageBoy);
-    balance_activities(pi, 7000, 40000, &get_training_study_array());
+    weigh_activities(pi, 7000, &get_training_study_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Training);
     pi.register_input_on_tick(40000, ActivityTypes::Flirt);
     pi.register_input_on_tick(40000, HousingTypes::LargeCloset);
@@ -430,8 +450,8 @@ pub fn rebirth_24() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 7000, &get_run_study_array());
-    balance_activities(pi, 7000, 40000, &get_training_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
+    weigh_activities(pi, 7000, &get_training_study_weights());
     pi.register_input_on_tick(10000, WorkTypes::BaggageBoy);
     pi.register_input_on_tick(40000, ActivityTypes::Training);
     pi.register_input_on_tick(45000, ActivityTypes::Flirt);
@@ -460,7 +480,7 @@ pub fn rebirth_28() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 50000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(40000, AutoSettingTypes::AutoWorkFalse);
     pi.register_input_on_tick(10000, WorkTypes::Mines);
     pi.register_input_on_tick(45000, ActivityTypes::Flirt);
@@ -489,10 +509,10 @@ pub fn rebirth_35() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 5000, &get_run_study_array());
-    balance_activities(pi, 5000, 40000, &get_training_study_wargames_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
+    weigh_activities(pi, 5000, &get_training_study_wargames_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Run);
-    balance_activities(pi, 45000, 60000, &get_training_study_wargames_array());
+    weigh_activities(pi, 45000, &get_training_study_wargames_weights());
     pi.register_input_on_tick(10000, WorkTypes::BaggageBoy);
     pi.register_input_on_tick(60000, ActivityTypes::Flirt);
     pi.register_input_on_tick(60000, AutoSettingTypes::AutoLivingFalse);
@@ -519,10 +539,10 @@ pub fn rebirth_36() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 5000, &get_run_study_array());
-    balance_activities(pi, 5000, 40000, &get_training_study_wargames_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
+    weigh_activities(pi, 5000, &get_training_study_wargames_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Run);
-    balance_activities(pi, 45000, 60000, &get_training_study_wargames_array());
+    weigh_activities(pi, 45000, &get_training_study_wargames_weights());
     pi.register_input_on_tick(10000, WorkTypes::BaggageBoy);
     pi.register_input_on_tick(60000, ActivityTypes::Flirt);
     pi.register_input_on_tick(60000, AutoSettingTypes::AutoLivingFalse);
@@ -551,10 +571,10 @@ pub fn rebirth_39() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 5000, &get_run_study_array());
-    balance_activities(pi, 5000, 40000, &get_training_study_wargames_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
+    weigh_activities(pi, 5000, &get_training_study_wargames_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Run);
-    balance_activities(pi, 45000, 60000, &get_training_study_wargames_array());
+    weigh_activities(pi, 45000, &get_training_study_wargames_weights());
     pi.register_input_on_tick(10000, WorkTypes::BaggageBoy);
     pi.register_input_on_tick(60000, ActivityTypes::Flirt);
     pi.register_input_on_tick(60000, AutoSettingTypes::AutoLivingFalse);
@@ -583,11 +603,11 @@ pub fn rebirth_55() -> GameSave {
     set_full_auto(&mut game_save.meta_data.options);
     state.life_stats.replaying = true;
     let pi = &mut game_save.previous_inputs;
-    balance_activities(pi, 4000, 5000, &get_run_study_array());
+    weigh_activities(pi, 4000, &get_run_study_weights());
     pi.register_input_on_tick(10000, WorkTypes::BaggageBoy);
-    balance_activities(pi, 5000, 40000, &get_training_meditate_array());
+    weigh_activities(pi, 5000, &get_training_meditate_weights());
     pi.register_input_on_tick(40000, ActivityTypes::Run);
-    balance_activities(pi, 45000, 60000, &get_training_meditate_array());
+    weigh_activities(pi, 45000, &get_training_meditate_weights());
     pi.register_input_on_tick(60000, ActivityTypes::Flirt);
     pi.register_input_on_tick(60000, AutoSettingTypes::AutoLivingFalse);
     pi.register_input_on_tick(60000, HousingTypes::SmallApartment);
@@ -0,0 +1,100 @@
+use crate::state::state_container::StateContainer;
+use serde::Serialize;
+
+pub const DEFAULT_SAMPLE_INTERVAL: u32 = 30;
+// When the series grows past this, every other sample is dropped and the interval doubles
+pub const MAX_SAMPLES: usize = 1000;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub tick: u32,
+    pub money: f64,
+    pub income: f64,
+    pub health: f64,
+    pub happiness: f64,
+    pub coins_gain: f64,
+}
+
+impl Sample {
+    pub fn new(state: &StateContainer) -> Sample {
+        Sample {
+            tick: state.life_stats.current_tick,
+            money: state.items.money,
+            income: state.items.income,
+            health: state.life_stats.health,
+            happiness: state.life_stats.happiness,
+            coins_gain: state.rebirth_stats.coins_gain,
+        }
+    }
+}
+
+// Curves of the current life, for charting and tuning strategies
+#[derive(Clone, Debug)]
+pub struct Sampler {
+    base_interval: u32,
+    interval: u32,
+    samples: Vec<Sample>,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Sampler::new(DEFAULT_SAMPLE_INTERVAL)
+    }
+}
+
+impl Sampler {
+    pub fn new(interval: u32) -> Sampler {
+        let interval = interval.max(1);
+        Sampler {
+            base_interval: interval,
+            interval,
+            samples: Vec::with_capacity(MAX_SAMPLES + 1),
+        }
+    }
+
+    pub fn set_interval(&mut self, interval: u32) {
+        *self = Sampler::new(interval);
+    }
+
+    // The interval currently in use, which grows as the life gets longer
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.interval = self.base_interval;
+    }
+
+    pub fn record(&mut self, state: &StateContainer) {
+        let tick = state.life_stats.current_tick;
+        if let Some(last) = self.samples.last() {
+            // The tick went back, so this is a new life
+            if tick <= last.tick {
+                self.clear();
+            } else if tick / self.interval == last.tick / self.interval {
+                return;
+            }
+        }
+        self.samples.push(Sample::new(state));
+        if self.samples.len() > MAX_SAMPLES {
+            self.downsample();
+        }
+    }
+
+    fn downsample(&mut self) {
+        self.interval *= 2;
+        let mut last_bucket = None;
+        let interval = self.interval;
+        self.samples.retain(|sample| {
+            let bucket = sample.tick / interval;
+            let keep = last_bucket != Some(bucket);
+            last_bucket = Some(bucket);
+            keep
+        });
+    }
+}
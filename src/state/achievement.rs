@@ -0,0 +1,28 @@
+use crate::input::achievement::{AchievementTypes, ACHIEVEMENT_SIZE};
+use serde::{Deserialize, Serialize};
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Achievement {
+    pub name: AchievementTypes,
+    pub is_unlocked: bool,
+}
+
+impl Achievement {
+    pub fn new(achievement: AchievementTypes) -> Achievement {
+        Achievement {
+            name: achievement,
+            is_unlocked: false,
+        }
+    }
+}
+
+pub fn get_achievements() -> [Achievement; ACHIEVEMENT_SIZE] {
+    let mut achievements: [MaybeUninit<Achievement>; ACHIEVEMENT_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in AchievementTypes::iter() {
+        achievements[name as usize].write(Achievement::new(name));
+    }
+    unsafe { mem::transmute(achievements) }
+}
@@ -6,7 +6,7 @@ use strum::IntoEnumIterator;
 
 use crate::input::boost_item::{BoostItemTypes, BOOST_ITEM_SIZE};
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct BoostItem {
     pub name: BoostItemTypes,
     pub is_purchased: bool,
@@ -0,0 +1,31 @@
+use crate::input::challenge::{ChallengeTypes, CHALLENGE_SIZE};
+use serde::{Deserialize, Serialize};
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Challenge {
+    pub name: ChallengeTypes,
+    pub is_completed: bool,
+    // Most coins gained in a life played under the challenge
+    pub best_coins_gain: f64,
+}
+
+impl Challenge {
+    pub fn new(challenge: ChallengeTypes) -> Challenge {
+        Challenge {
+            name: challenge,
+            is_completed: false,
+            best_coins_gain: 0.0,
+        }
+    }
+}
+
+pub fn get_challenges() -> [Challenge; CHALLENGE_SIZE] {
+    let mut challenges: [MaybeUninit<Challenge>; CHALLENGE_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in ChallengeTypes::iter() {
+        challenges[name as usize].write(Challenge::new(name));
+    }
+    unsafe { mem::transmute(challenges) }
+}
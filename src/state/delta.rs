@@ -0,0 +1,142 @@
+use super::activity::Activity;
+use super::blessing::Blessing;
+use super::boost_item::BoostItem;
+use super::housing::Housing;
+use super::items::Items;
+use super::life_event::LifeEvent;
+use super::life_stats::LifeStats;
+use super::rebirth_stats::RebirthStats;
+use super::rng::Rng;
+use super::skill::Skill;
+use super::state_container::StateContainer;
+use super::stats::Stat;
+use super::tomb::Tomb;
+use super::work::Work;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DeltaEntry<T> {
+    pub index: usize,
+    pub value: T,
+}
+
+// What changed in the state since the last delta was taken. The frontend patches
+// its copy of the state with it instead of fetching the whole state every frame.
+// When there is nothing to compare against, `full` holds the whole state.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct StateDelta {
+    pub full: Option<Box<StateContainer>>,
+    pub life_stats: Option<LifeStats>,
+    pub items: Option<Items>,
+    pub rebirth_stats: Option<Box<RebirthStats>>,
+    pub rng: Option<Rng>,
+    pub stats: Vec<DeltaEntry<Stat>>,
+    pub works: Vec<DeltaEntry<Work>>,
+    pub activities: Vec<DeltaEntry<Activity>>,
+    pub housing: Vec<DeltaEntry<Housing>>,
+    pub tombs: Vec<DeltaEntry<Tomb>>,
+    pub blessings: Vec<DeltaEntry<Blessing>>,
+    pub skills: Vec<DeltaEntry<Skill>>,
+    pub boost_items: Vec<DeltaEntry<BoostItem>>,
+    pub life_events: Vec<DeltaEntry<LifeEvent>>,
+}
+
+impl StateDelta {
+    pub fn full(state: &StateContainer) -> StateDelta {
+        StateDelta {
+            full: Some(Box::new(state.clone())),
+            ..Default::default()
+        }
+    }
+
+    pub fn diff(old: &StateContainer, new: &StateContainer) -> StateDelta {
+        StateDelta {
+            full: None,
+            life_stats: diff_value(&old.life_stats, &new.life_stats),
+            items: diff_value(&old.items, &new.items),
+            rebirth_stats: diff_value(&old.rebirth_stats, &new.rebirth_stats).map(Box::new),
+            rng: diff_value(&old.rng, &new.rng),
+            stats: diff_entries(&old.stats, &new.stats),
+            works: diff_entries(&old.works, &new.works),
+            activities: diff_entries(&old.activities, &new.activities),
+            housing: diff_entries(&old.housing, &new.housing),
+            tombs: diff_entries(&old.tombs, &new.tombs),
+            blessings: diff_entries(&old.blessings, &new.blessings),
+            skills: diff_entries(&old.skills, &new.skills),
+            boost_items: diff_entries(&old.boost_items, &new.boost_items),
+            life_events: diff_entries(&old.life_events, &new.life_events),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.full.is_none()
+            && self.life_stats.is_none()
+            && self.items.is_none()
+            && self.rebirth_stats.is_none()
+            && self.rng.is_none()
+            && self.stats.is_empty()
+            && self.works.is_empty()
+            && self.activities.is_empty()
+            && self.housing.is_empty()
+            && self.tombs.is_empty()
+            && self.blessings.is_empty()
+            && self.skills.is_empty()
+            && self.boost_items.is_empty()
+            && self.life_events.is_empty()
+    }
+
+    // Patches state the same way the frontend does
+    pub fn apply(&self, state: &mut StateContainer) {
+        if let Some(full) = &self.full {
+            *state = *full.clone();
+            return;
+        }
+        if let Some(life_stats) = &self.life_stats {
+            state.life_stats = life_stats.clone();
+        }
+        if let Some(items) = &self.items {
+            state.items = items.clone();
+        }
+        if let Some(rebirth_stats) = &self.rebirth_stats {
+            state.rebirth_stats = *rebirth_stats.clone();
+        }
+        if let Some(rng) = &self.rng {
+            state.rng = rng.clone();
+        }
+        apply_entries(&self.stats, &mut state.stats);
+        apply_entries(&self.works, &mut state.works);
+        apply_entries(&self.activities, &mut state.activities);
+        apply_entries(&self.housing, &mut state.housing);
+        apply_entries(&self.tombs, &mut state.tombs);
+        apply_entries(&self.blessings, &mut state.blessings);
+        apply_entries(&self.skills, &mut state.skills);
+        apply_entries(&self.boost_items, &mut state.boost_items);
+        apply_entries(&self.life_events, &mut state.life_events);
+    }
+}
+
+fn diff_value<T: PartialEq + Clone>(old: &T, new: &T) -> Option<T> {
+    if old == new {
+        None
+    } else {
+        Some(new.clone())
+    }
+}
+
+fn diff_entries<T: PartialEq + Clone>(old: &[T], new: &[T]) -> Vec<DeltaEntry<T>> {
+    old.iter()
+        .zip(new.iter())
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(index, (_, new))| DeltaEntry {
+            index,
+            value: new.clone(),
+        })
+        .collect()
+}
+
+fn apply_entries<T: Clone>(entries: &[DeltaEntry<T>], values: &mut [T]) {
+    for entry in entries {
+        values[entry.index] = entry.value.clone();
+    }
+}
@@ -2,12 +2,15 @@
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Items {
     pub money: f64,
     pub income: f64,
     pub divine_favor: f64,
     pub divine_favor_rate: f64,
+    // Most money held at once this life
+    #[serde(default)]
+    pub max_money: f64,
 }
 
 impl Items {
@@ -17,6 +20,7 @@ impl Items {
             income: 0.0,
             divine_favor: 0.0,
             divine_favor_rate: 0.0,
+            max_money: 0.0,
         }
     }
 }
@@ -0,0 +1,32 @@
+use crate::input::karma_upgrade::{KarmaUpgradeTypes, KARMA_UPGRADE_SIZE};
+use serde::{Deserialize, Serialize};
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct KarmaUpgrade {
+    pub name: KarmaUpgradeTypes,
+    pub is_purchased: bool,
+    pub is_unlocked: bool,
+    pub is_visible: bool,
+}
+
+impl KarmaUpgrade {
+    pub fn new(karma_upgrade: KarmaUpgradeTypes) -> KarmaUpgrade {
+        KarmaUpgrade {
+            name: karma_upgrade,
+            is_purchased: false,
+            is_unlocked: false,
+            is_visible: false,
+        }
+    }
+}
+
+pub fn get_karma_upgrades() -> [KarmaUpgrade; KARMA_UPGRADE_SIZE] {
+    let mut karma_upgrades: [MaybeUninit<KarmaUpgrade>; KARMA_UPGRADE_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in KarmaUpgradeTypes::iter() {
+        karma_upgrades[name as usize].write(KarmaUpgrade::new(name));
+    }
+    unsafe { mem::transmute(karma_upgrades) }
+}
@@ -0,0 +1,33 @@
+use crate::input::life_event::{LifeEventTypes, LIFE_EVENT_SIZE};
+use serde::{Deserialize, Serialize};
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LifeEvent {
+    pub name: LifeEventTypes,
+    pub is_active: bool,
+    // The tick the effect wears off
+    pub active_until: u32,
+    pub times_happened: u32,
+}
+
+impl LifeEvent {
+    pub fn new(life_event: LifeEventTypes) -> LifeEvent {
+        LifeEvent {
+            name: life_event,
+            is_active: false,
+            active_until: 0,
+            times_happened: 0,
+        }
+    }
+}
+
+pub fn get_life_events() -> [LifeEvent; LIFE_EVENT_SIZE] {
+    let mut life_events: [MaybeUninit<LifeEvent>; LIFE_EVENT_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in LifeEventTypes::iter() {
+        life_events[name as usize].write(LifeEvent::new(name));
+    }
+    unsafe { mem::transmute(life_events) }
+}
@@ -0,0 +1,91 @@
+use super::state_container::StateContainer;
+use crate::input::work::{WorkCategoryTypes, WorkTypes};
+use crate::WORLD;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use strum::IntoEnumIterator;
+
+// Older lives are dropped once the history is full
+pub const MAX_LIFE_HISTORY: usize = 100;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HighestJob {
+    pub category: WorkCategoryTypes,
+    pub work: WorkTypes,
+    pub level: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LifeSummary {
+    pub rebirth_count: u32,
+    pub tier: u32,
+    pub age: f64, // days
+    pub ticks: u32,
+    pub coins_gain: f64,
+    pub max_money: f64,
+    // The furthest job worked in every category that was worked at all
+    pub highest_jobs: Vec<HighestJob>,
+    pub items_bought: u32,
+    pub tombs_bought: u32,
+    pub blessing_levels: u32,
+}
+
+impl LifeSummary {
+    pub fn new(state: &StateContainer, coins_gain: f64) -> LifeSummary {
+        let highest_jobs = WorkCategoryTypes::iter()
+            .filter_map(|category| {
+                state
+                    .works
+                    .iter()
+                    .filter(|work| {
+                        work.level > 0 && WORLD.get_work(work.name).work_type == category
+                    })
+                    .max_by_key(|work| work.name as usize)
+                    .map(|work| HighestJob {
+                        category,
+                        work: work.name,
+                        level: work.level,
+                    })
+            })
+            .collect();
+        LifeSummary {
+            rebirth_count: state.rebirth_stats.rebirth_count,
+            tier: state.rebirth_stats.tier,
+            age: state.life_stats.age,
+            ticks: state.life_stats.current_tick,
+            coins_gain,
+            max_money: state.items.max_money,
+            highest_jobs,
+            items_bought: state.boost_items.iter().filter(|i| i.is_purchased).count() as u32,
+            tombs_bought: state.tombs.iter().filter(|t| t.is_purchased).count() as u32,
+            blessing_levels: state.blessings.iter().map(|b| b.level).sum(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LifeHistory {
+    lives: VecDeque<LifeSummary>,
+}
+
+impl LifeHistory {
+    pub fn push(&mut self, summary: LifeSummary) {
+        if self.lives.len() >= MAX_LIFE_HISTORY {
+            self.lives.pop_front();
+        }
+        self.lives.push_back(summary);
+    }
+
+    // Oldest life first
+    pub fn lives(&self) -> &VecDeque<LifeSummary> {
+        &self.lives
+    }
+
+    pub fn len(&self) -> usize {
+        self.lives.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lives.is_empty()
+    }
+}
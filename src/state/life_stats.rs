@@ -1,11 +1,12 @@
 use super::rebirth_stats::RebirthStats;
+use crate::input::challenge::ChallengeTypes;
 use crate::WORLD;
 use serde::{Deserialize, Serialize};
 
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct LifeStats {
     pub age: f64,      // days
     pub lifespan: f64, // days
@@ -16,6 +17,13 @@ pub struct LifeStats {
     pub dead: bool,
     pub current_tick: u32,
     pub replaying: bool,
+    // Living on the StoneFloor because the upkeep of the chosen housing can't be paid
+    #[serde(default)]
+    pub evicted: bool,
+    // The challenge whose rules this life is played under
+    #[wasm_bindgen(skip)]
+    #[serde(default)]
+    pub challenge: Option<ChallengeTypes>,
 }
 
 impl LifeStats {
@@ -31,6 +39,8 @@ impl LifeStats {
             dead: false,
             current_tick: 0,
             replaying: false,
+            evicted: false,
+            challenge: None,
         }
     }
 }
@@ -1,11 +1,19 @@
+pub mod achievement;
 pub mod activity;
 pub mod blessing;
 pub mod boost_item;
+pub mod challenge;
+pub mod delta;
 pub mod housing;
 pub mod items;
+pub mod karma_upgrade;
+pub mod life_event;
+pub mod life_history;
 pub mod life_stats;
+pub mod padded;
 pub mod rebirth_stats;
 pub mod rebirth_upgrade;
+pub mod rng;
 pub mod skill;
 pub mod state_container;
 pub mod stats;
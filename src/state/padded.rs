@@ -0,0 +1,75 @@
+// Saves store the state arrays by position, so a save made before new entries
+// were appended to an enum has shorter arrays than the current build.
+// These deserializers fill the missing tail with the defaults, which only works
+// as long as new variants are added at the end of their enum.
+use super::blessing::{get_blessings, Blessing};
+use super::boost_item::{get_boost_items, BoostItem};
+use super::rebirth_upgrade::{get_rebirth_upgrades, RebirthUpgrade};
+use super::skill::{get_skills, Skill};
+use super::tomb::{get_tombs, Tomb};
+use super::work::{get_works, Work};
+use crate::input::blessing::BLESSING_SIZE;
+use crate::input::boost_item::BOOST_ITEM_SIZE;
+use crate::input::rebirth_upgrade::REBIRTH_UPGRADE_SIZE;
+use crate::input::skill::SKILL_SIZE;
+use crate::input::tomb::TOMB_SIZE;
+use crate::input::work::WORK_SIZE;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+pub fn deserialize_padded<'de, D, T, const N: usize>(
+    deserializer: D,
+    mut defaults: [T; N],
+) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let saved = Vec::<T>::deserialize(deserializer)?;
+    if saved.len() > N {
+        return Err(D::Error::invalid_length(
+            saved.len(),
+            &format!("at most {} entries", N).as_str(),
+        ));
+    }
+    for (entry, value) in defaults.iter_mut().zip(saved) {
+        *entry = value;
+    }
+    Ok(defaults)
+}
+
+pub fn rebirth_upgrades<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<[RebirthUpgrade; REBIRTH_UPGRADE_SIZE], D::Error> {
+    deserialize_padded(deserializer, get_rebirth_upgrades())
+}
+
+pub fn works<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[Work; WORK_SIZE], D::Error> {
+    deserialize_padded(deserializer, get_works())
+}
+
+pub fn max_job_levels<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<[u32; WORK_SIZE], D::Error> {
+    deserialize_padded(deserializer, [0; WORK_SIZE])
+}
+
+pub fn boost_items<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<[BoostItem; BOOST_ITEM_SIZE], D::Error> {
+    deserialize_padded(deserializer, get_boost_items())
+}
+
+pub fn tombs<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[Tomb; TOMB_SIZE], D::Error> {
+    deserialize_padded(deserializer, get_tombs())
+}
+
+pub fn blessings<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<[Blessing; BLESSING_SIZE], D::Error> {
+    deserialize_padded(deserializer, get_blessings())
+}
+
+pub fn skills<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[Skill; SKILL_SIZE], D::Error> {
+    deserialize_padded(deserializer, get_skills())
+}
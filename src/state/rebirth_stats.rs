@@ -1,11 +1,19 @@
 #![allow(non_snake_case)]
+use super::achievement::{get_achievements, Achievement};
+use super::challenge::{get_challenges, Challenge};
+use super::karma_upgrade::{get_karma_upgrades, KarmaUpgrade};
+use super::life_history::LifeHistory;
+use super::padded;
 use super::rebirth_upgrade::{get_rebirth_upgrades, RebirthUpgrade};
+use crate::input::achievement::ACHIEVEMENT_SIZE;
+use crate::input::challenge::CHALLENGE_SIZE;
+use crate::input::karma_upgrade::KARMA_UPGRADE_SIZE;
 use crate::input::rebirth_upgrade::REBIRTH_UPGRADE_SIZE;
 use crate::input::work::WORK_SIZE;
 use serbia::serbia;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
 pub struct Unlocks {
     pub can_end_early: bool,
     pub can_auto_work: bool,
@@ -23,8 +31,48 @@ pub struct Unlocks {
     pub has_meditation: bool,
 }
 
+// One of the Unlocks, for requirements kept as data
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum UnlockTypes {
+    CanEndEarly,
+    CanAutoWork,
+    CanAutoLiving,
+    CanAutoBuyItem,
+    CanAutoBuyBlessing,
+    CanAutoBuyTomb,
+    CanAutoRebirth,
+    CanAutoEndEarly,
+    CanQueueItem,
+    CanReplay,
+    HasFaith,
+    HasSkills,
+    HasMilitaryTactics,
+    HasMeditation,
+}
+
+impl Unlocks {
+    pub fn is_unlocked(&self, unlock: UnlockTypes) -> bool {
+        match unlock {
+            UnlockTypes::CanEndEarly => self.can_end_early,
+            UnlockTypes::CanAutoWork => self.can_auto_work,
+            UnlockTypes::CanAutoLiving => self.can_auto_living,
+            UnlockTypes::CanAutoBuyItem => self.can_auto_buy_item,
+            UnlockTypes::CanAutoBuyBlessing => self.can_auto_buy_blessing,
+            UnlockTypes::CanAutoBuyTomb => self.can_auto_buy_tomb,
+            UnlockTypes::CanAutoRebirth => self.can_auto_rebirth,
+            UnlockTypes::CanAutoEndEarly => self.can_auto_end_early,
+            UnlockTypes::CanQueueItem => self.can_queue_item,
+            UnlockTypes::CanReplay => self.can_replay,
+            UnlockTypes::HasFaith => self.has_faith,
+            UnlockTypes::HasSkills => self.has_skills,
+            UnlockTypes::HasMilitaryTactics => self.has_military_tactics,
+            UnlockTypes::HasMeditation => self.has_meditation,
+        }
+    }
+}
+
 #[serbia]
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct RebirthStats {
     pub rebirth_count: u32,
     pub tier: u32,
@@ -32,9 +80,19 @@ pub struct RebirthStats {
     pub coins_gain: f64,
     pub karma: f64,
     pub time_factor: f64,
+    #[serde(deserialize_with = "padded::max_job_levels")]
     pub max_job_levels: [u32; WORK_SIZE],
+    #[serde(deserialize_with = "padded::rebirth_upgrades")]
     pub rebirth_upgrades: [RebirthUpgrade; REBIRTH_UPGRADE_SIZE],
+    #[serde(default = "get_karma_upgrades")]
+    pub karma_upgrades: [KarmaUpgrade; KARMA_UPGRADE_SIZE],
     pub unlocks: Unlocks,
+    #[serde(default)]
+    pub history: LifeHistory,
+    #[serde(default = "get_achievements")]
+    pub achievements: [Achievement; ACHIEVEMENT_SIZE],
+    #[serde(default = "get_challenges")]
+    pub challenges: [Challenge; CHALLENGE_SIZE],
 }
 
 impl Default for RebirthStats {
@@ -48,7 +106,11 @@ impl Default for RebirthStats {
             time_factor: 1.0,
             max_job_levels: [0; WORK_SIZE],
             rebirth_upgrades: get_rebirth_upgrades(),
+            karma_upgrades: get_karma_upgrades(),
             unlocks: Unlocks::default(),
+            history: LifeHistory::default(),
+            achievements: get_achievements(),
+            challenges: get_challenges(),
         }
     }
 }
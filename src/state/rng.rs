@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+// Small seeded PRNG (mulberry32). It is saved with the state, so a life played
+// again from the same seed draws the same numbers in the same order.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct Rng {
+    pub seed: u32,
+    state: u32,
+}
+
+impl Rng {
+    pub fn new(seed: u32) -> Rng {
+        Rng { seed, state: seed }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self.state.wrapping_add(0x6D2B_79F5);
+        let mut z = self.state;
+        z = (z ^ (z >> 15)).wrapping_mul(z | 1);
+        z ^= z.wrapping_add((z ^ (z >> 7)).wrapping_mul(z | 61));
+        z ^ (z >> 14)
+    }
+
+    // Uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u32() as f64 / 4_294_967_296.0
+    }
+}
+
+// Seed for a new life, mixing the time it starts at with the rebirth count so
+// two lives started in the same millisecond still differ
+pub fn new_seed(now: f64, rebirth_count: u32) -> u32 {
+    let time = now as u64;
+    let mut rng = Rng::new((time as u32) ^ ((time >> 32) as u32));
+    rng.next_u32() ^ rebirth_count.wrapping_mul(0x9E37_79B9)
+}
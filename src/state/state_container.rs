@@ -5,8 +5,11 @@ use super::blessing::{get_blessings, Blessing};
 use super::boost_item::{get_boost_items, BoostItem};
 use super::housing::{get_housings, Housing};
 use super::items::Items;
+use super::life_event::{get_life_events, LifeEvent};
 use super::life_stats::LifeStats;
+use super::padded;
 use super::rebirth_stats::RebirthStats;
+use super::rng::Rng;
 use super::skill::{get_skills, Skill};
 use super::stats::Stat;
 use super::tomb::{get_tombs, Tomb};
@@ -15,6 +18,7 @@ use crate::input::activity::ACTIVITY_SIZE;
 use crate::input::blessing::BLESSING_SIZE;
 use crate::input::boost_item::BOOST_ITEM_SIZE;
 use crate::input::housing::HOUSING_SIZE;
+use crate::input::life_event::LIFE_EVENT_SIZE;
 use crate::input::rebirth_upgrade::RebirthUpgradeTypes;
 use crate::input::skill::SKILL_SIZE;
 use crate::input::stat::STAT_SIZE;
@@ -28,19 +32,29 @@ use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
 #[serbia]
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct StateContainer {
     pub stats: [Stat; STAT_SIZE],
     pub rebirth_stats: RebirthStats,
     pub life_stats: LifeStats,
     pub items: Items,
+    #[serde(deserialize_with = "padded::works")]
     pub works: [Work; WORK_SIZE],
     pub activities: [Activity; ACTIVITY_SIZE],
     pub housing: [Housing; HOUSING_SIZE],
+    #[serde(deserialize_with = "padded::tombs")]
     pub tombs: [Tomb; TOMB_SIZE],
+    #[serde(deserialize_with = "padded::blessings")]
     pub blessings: [Blessing; BLESSING_SIZE],
+    #[serde(deserialize_with = "padded::skills")]
     pub skills: [Skill; SKILL_SIZE],
+    #[serde(deserialize_with = "padded::boost_items")]
     pub boost_items: [BoostItem; BOOST_ITEM_SIZE],
+    #[serde(default = "get_life_events")]
+    pub life_events: [LifeEvent; LIFE_EVENT_SIZE],
+    // The first life always starts from seed 0, later ones get theirs at rebirth
+    #[serde(default)]
+    pub rng: Rng,
 }
 
 impl Default for StateContainer {
@@ -69,6 +83,8 @@ pub fn rebirth(rebirth_stats: RebirthStats) -> StateContainer {
         blessings: get_blessings(),
         skills: get_skills(),
         boost_items: get_boost_items(),
+        life_events: get_life_events(),
+        rng: Rng::default(),
     };
     for upgrade_type in RebirthUpgradeTypes::iter() {
         if state.rebirth_stats.rebirth_upgrades[upgrade_type as usize].is_purchased {
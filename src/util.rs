@@ -1,6 +1,6 @@
 use crate::engine::{character_death_update, engine_run};
 use crate::game::Game;
-use crate::input::activity::ActivityTypes;
+use crate::input::activity::{ActivityTypes, ActivityWeight};
 use crate::input::options::Options;
 use crate::input::work::WorkTypes;
 use crate::input::Input;
@@ -57,60 +57,39 @@ pub fn set_full_auto(options: &mut Options) {
     options.auto_buy_blessing = true;
 }
 
-pub fn balance_activities(
-    inputs: &mut Inputs,
-    start_tick: u32,
-    end_tick: u32,
-    activities: &[ActivityTypes],
-) {
-    let increment = 200;
-    let mut tick = start_tick;
-    let mut i = 0usize;
-    while tick < end_tick - increment {
-        inputs.register_input_on_tick(tick, activities[i % activities.len()]);
-        tick += increment;
-        i += 1;
+// Splits the time between the activities from the tick on, by weights
+// relative to each other. The first activity is set on its own before, which
+// drops an earlier split. Setting a single activity afterwards ends the split.
+pub fn weigh_activities(inputs: &mut Inputs, tick: u32, weights: &[(ActivityTypes, u32)]) {
+    inputs.register_input_on_tick(tick, weights[0].0);
+    for &(activity, weight) in weights {
+        inputs.register_input_on_tick(tick, ActivityWeight { activity, weight });
     }
 }
-pub fn get_training_meditate_array() -> [ActivityTypes; 8] {
+
+pub fn get_training_meditate_weights() -> [(ActivityTypes, u32); 4] {
     [
-        ActivityTypes::Training,
-        ActivityTypes::Studying,
-        ActivityTypes::Meditate,
-        ActivityTypes::Training,
-        ActivityTypes::WarGames,
-        ActivityTypes::Training,
-        ActivityTypes::Studying,
-        ActivityTypes::Training,
+        (ActivityTypes::Training, 4),
+        (ActivityTypes::Studying, 2),
+        (ActivityTypes::Meditate, 1),
+        (ActivityTypes::WarGames, 1),
     ]
 }
 
-pub fn get_training_study_wargames_array() -> [ActivityTypes; 7] {
+pub fn get_training_study_wargames_weights() -> [(ActivityTypes, u32); 3] {
     [
-        ActivityTypes::Training,
-        ActivityTypes::Studying,
-        ActivityTypes::Training,
-        ActivityTypes::WarGames,
-        ActivityTypes::Training,
-        ActivityTypes::Studying,
-        ActivityTypes::Training,
+        (ActivityTypes::Training, 4),
+        (ActivityTypes::Studying, 2),
+        (ActivityTypes::WarGames, 1),
     ]
 }
 
-pub fn get_training_study_array() -> [ActivityTypes; 3] {
-    [
-        ActivityTypes::Training,
-        ActivityTypes::Studying,
-        ActivityTypes::Training,
-    ]
+pub fn get_training_study_weights() -> [(ActivityTypes, u32); 2] {
+    [(ActivityTypes::Training, 2), (ActivityTypes::Studying, 1)]
 }
 
-pub fn get_run_study_array() -> [ActivityTypes; 3] {
-    [
-        ActivityTypes::Run,
-        ActivityTypes::Studying,
-        ActivityTypes::Run,
-    ]
+pub fn get_run_study_weights() -> [(ActivityTypes, u32); 2] {
+    [(ActivityTypes::Run, 2), (ActivityTypes::Studying, 1)]
 }
 
 pub fn set_lower_tier_jobs_to(rebirth_stats: &mut RebirthStats, level: u32) {
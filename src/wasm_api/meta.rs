@@ -2,6 +2,7 @@
 
 use crate::game::{Game, GameSave};
 use crate::GLOBAL_DATA;
+use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
 use libflate::gzip::{Decoder, Encoder};
 use log::info;
@@ -126,7 +127,8 @@ pub fn do_save(game: &mut Game) {
         local_storage
             .set_item("save", &to_string(&GameSave::from(&*game)).unwrap())
             .unwrap();
-        game.meta_data.set_save_time();
+        let now = game.clock.now();
+        game.meta_data.set_save_time(now);
     }
 }
 
@@ -137,8 +139,13 @@ pub fn load() {
     if let Ok(Some(local_storage)) = window.local_storage() {
         match local_storage.get_item("save").unwrap() {
             Some(json_save) => {
-                if let Ok(save) = from_str::<GameSave>(&json_save) {
-                    current_game.load_game(save);
+                match from_str::<GameSave>(&json_save) {
+                    Ok(save) => current_game.load_game(save),
+                    Err(err) => {
+                        // Keep the save around, the next autosave overwrites it
+                        info!("Could not load save: {:?}", err);
+                        local_storage.set_item("save_backup", &json_save).unwrap();
+                    }
                 }
             }
             None => info!("You don't have a game to load"),
@@ -150,14 +157,8 @@ pub fn load() {
 #[wasm_bindgen]
 pub fn export_save() -> String {
     let game: &Game = &GLOBAL_DATA.lock().unwrap();
-    // let game = GLOBAL_DATA.lock().unwrap();
     info!("exporting game");
-    let json_data = to_string(&GameSave::from(game)).unwrap();
-
-    let mut encoder = Encoder::new(Vec::new()).unwrap();
-    encoder.write_all(json_data.as_bytes()).unwrap();
-    let res = encoder.finish().into_result().unwrap();
-    let b64 = general_purpose::STANDARD.encode(res);
+    let b64 = encode_save(&GameSave::from(game));
     info!("{}", &b64);
     b64
 }
@@ -165,17 +166,26 @@ pub fn export_save() -> String {
 #[wasm_bindgen]
 pub fn import_save(save: String) {
     let mut current_game = GLOBAL_DATA.lock().unwrap();
-    let data = general_purpose::STANDARD.decode(save).unwrap();
-    let mut decoder = Decoder::new(&data[..]).unwrap();
-    let mut decoded_data = Vec::new();
-    decoder.read_to_end(&mut decoded_data).unwrap();
-    let save_state = match str::from_utf8(decoded_data.as_slice()) {
-        Ok(v) => v,
-        Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
-    };
-
-    info!("{}", save_state);
-    if let Ok(save) = from_str::<GameSave>(save_state) {
-        current_game.load_game(save);
+    match decode_save(&save) {
+        Ok(save) => current_game.load_game(save),
+        Err(err) => info!("{:?}", err),
     }
 }
+
+pub fn encode_save(save: &GameSave) -> String {
+    let json_data = to_string(save).unwrap();
+
+    let mut encoder = Encoder::new(Vec::new()).unwrap();
+    encoder.write_all(json_data.as_bytes()).unwrap();
+    let res = encoder.finish().into_result().unwrap();
+    general_purpose::STANDARD.encode(res)
+}
+
+pub fn decode_save(save: &str) -> Result<GameSave> {
+    let data = general_purpose::STANDARD.decode(save.trim())?;
+    let mut decoder = Decoder::new(&data[..])?;
+    let mut decoded_data = Vec::new();
+    decoder.read_to_end(&mut decoded_data)?;
+    let save_state = str::from_utf8(decoded_data.as_slice())?;
+    Ok(from_str::<GameSave>(save_state)?)
+}
@@ -0,0 +1,173 @@
+use crate::engine::value_keys::KeyValues;
+use crate::events::GameEventType;
+use crate::game::Game;
+use crate::input::achievement::{AchievementTypes, ACHIEVEMENT_SIZE};
+use crate::input::tomb::TOMB_SIZE;
+use crate::input::work::WorkCategoryTypes;
+use crate::WORLD;
+use serde::Serialize;
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+// Most rebirths RapidAscent can take to reach its tier
+const RAPID_ASCENT_REBIRTHS: u32 = 10;
+
+#[derive(Serialize)]
+pub struct Achievement {
+    pub name: AchievementTypes,
+    pub description: &'static str,
+    pub effect_description: &'static str,
+    pub display_name: &'static str,
+    // Unlocked once the progress reaches it
+    pub target: f64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AchievementProgress {
+    pub name: AchievementTypes,
+    pub is_unlocked: bool,
+    pub progress: f64,
+    pub target: f64,
+}
+
+impl Achievement {
+    pub fn get_achievement_gains(&self, game: &mut Game) {
+        if !game.state.rebirth_stats.achievements[self.name as usize].is_unlocked {
+            return;
+        }
+        let inter = &mut game.intermediate_state;
+        match self.name {
+            AchievementTypes::Journeyman => {
+                for category in WorkCategoryTypes::iter() {
+                    inter.add_multiplier(category.into(), 1.1, self.name);
+                }
+            }
+            AchievementTypes::Master => {
+                inter.add_multiplier(KeyValues::Stats, 1.1, self.name);
+            }
+            AchievementTypes::Undertaker => {
+                inter.add_multiplier(KeyValues::Coins, 1.1, self.name);
+            }
+            AchievementTypes::RapidAscent => {
+                inter.add_multiplier(KeyValues::Skills, 1.1, self.name);
+            }
+            AchievementTypes::DiedYoung => {
+                inter.add_multiplier(KeyValues::Happiness, 1.1, self.name);
+            }
+        }
+    }
+
+    pub fn progress(&self, game: &Game) -> f64 {
+        let state = &game.state;
+        match self.name {
+            AchievementTypes::Journeyman | AchievementTypes::Master => {
+                state.works.iter().map(|w| w.level).max().unwrap_or(0) as f64
+            }
+            AchievementTypes::Undertaker => {
+                state.tombs.iter().filter(|t| t.is_purchased).count() as f64
+            }
+            AchievementTypes::RapidAscent => {
+                if state.rebirth_stats.rebirth_count <= RAPID_ASCENT_REBIRTHS {
+                    state.rebirth_stats.tier as f64
+                } else {
+                    0.0
+                }
+            }
+            // Only known once the life is over
+            AchievementTypes::DiedYoung => {
+                let died_young = state.life_stats.dead && state.life_stats.age < 30.0 * 365.0;
+                if died_young {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+pub fn translate_achievement(achievement: AchievementTypes) -> Achievement {
+    match achievement {
+        AchievementTypes::Journeyman => Achievement {
+            name: achievement,
+            description: "Reach level 25 in any job",
+            effect_description: "All work XP 1.1x",
+            display_name: "Journeyman",
+            target: 25.0,
+        },
+        AchievementTypes::Master => Achievement {
+            name: achievement,
+            description: "Reach level 100 in any job",
+            effect_description: "Stat XP 1.1x",
+            display_name: "Master",
+            target: 100.0,
+        },
+        AchievementTypes::Undertaker => Achievement {
+            name: achievement,
+            description: "Own every tomb in one life",
+            effect_description: "Coins 1.1x",
+            display_name: "Undertaker",
+            target: TOMB_SIZE as f64,
+        },
+        AchievementTypes::RapidAscent => Achievement {
+            name: achievement,
+            description: "Reach tier 3 within 10 rebirths",
+            effect_description: "Skill XP 1.1x",
+            display_name: "Rapid Ascent",
+            target: 3.0,
+        },
+        AchievementTypes::DiedYoung => Achievement {
+            name: achievement,
+            description: "Die before the age of 30",
+            effect_description: "Happiness 1.1x",
+            display_name: "Live Fast",
+            target: 1.0,
+        },
+    }
+}
+
+pub fn get_achievements() -> [Achievement; ACHIEVEMENT_SIZE] {
+    let mut achievements: [MaybeUninit<Achievement>; ACHIEVEMENT_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in AchievementTypes::iter() {
+        achievements[name as usize].write(translate_achievement(name));
+    }
+    unsafe { mem::transmute(achievements) }
+}
+
+// Unlocks every achievement whose progress reached its target. Runs after every
+// step and once more at death.
+pub fn check_achievements(game: &mut Game) {
+    for achievement in WORLD.achievements.iter() {
+        let is_unlocked =
+            game.state.rebirth_stats.achievements[achievement.name as usize].is_unlocked;
+        if !is_unlocked && achievement.progress(game) >= achievement.target {
+            game.state.rebirth_stats.achievements[achievement.name as usize].is_unlocked = true;
+            game.push_event(GameEventType::AchievementUnlocked {
+                achievement: achievement.name,
+            });
+        }
+    }
+}
+
+pub fn get_achievement_progress(game: &Game) -> Vec<AchievementProgress> {
+    WORLD
+        .achievements
+        .iter()
+        .map(|achievement| {
+            let is_unlocked =
+                game.state.rebirth_stats.achievements[achievement.name as usize].is_unlocked;
+            let progress = if is_unlocked {
+                achievement.target
+            } else {
+                achievement.progress(game).min(achievement.target)
+            };
+            AchievementProgress {
+                name: achievement.name,
+                is_unlocked,
+                progress,
+                target: achievement.target,
+            }
+        })
+        .collect()
+}
@@ -19,16 +19,23 @@ pub struct Activity {
     pub required_tier: u32,
 }
 
-impl Gain for Activity {
-    fn gain(&self, intermediate: &mut IntermediateState) {
+impl Activity {
+    // Gains for spending only part of the time on this activity
+    pub fn gain_share(&self, intermediate: &mut IntermediateState, share: f64) {
         intermediate.set_base(
             self.gained_value_type,
-            self.base_gain_amount,
-            self.display_name,
+            self.base_gain_amount * share,
+            self.name,
         );
     }
 }
 
+impl Gain for Activity {
+    fn gain(&self, intermediate: &mut IntermediateState) {
+        self.gain_share(intermediate, 1.0);
+    }
+}
+
 pub fn translate_activity(activity: ActivityTypes) -> Activity {
     match activity {
         ActivityTypes::Training => Activity {
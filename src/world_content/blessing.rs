@@ -1,6 +1,7 @@
 use crate::engine::value_keys::KeyValues;
 use crate::game::Game;
 use crate::input::blessing::{BlessingTypes, BLESSING_SIZE};
+use crate::world_content::deity::{blessing_cost_growth, deity_allows_blessing};
 // use crate::state::blessing::Blessing as BlessingState;
 use crate::icon::{Icon, IconType};
 use serde::Serialize;
@@ -29,19 +30,27 @@ impl Blessing {
         match self.name {
             BlessingTypes::HeruclesStrength => {
                 let boost = get_multiplier(blessing_state.level);
-                inter.add_multiplier(KeyValues::Str, boost, self.display_name);
+                inter.add_multiplier(KeyValues::Str, boost, self.name);
             }
             BlessingTypes::AthenasWisdom => {
                 let boost = get_multiplier(blessing_state.level);
-                inter.add_multiplier(KeyValues::Int, boost, self.display_name);
+                inter.add_multiplier(KeyValues::Int, boost, self.name);
             }
             BlessingTypes::PoseidonsSturdiness => {
                 let boost = get_multiplier(blessing_state.level);
-                inter.add_multiplier(KeyValues::Con, boost, self.display_name);
+                inter.add_multiplier(KeyValues::Con, boost, self.name);
             }
             BlessingTypes::AfroditesCharm => {
                 let boost = get_multiplier(blessing_state.level);
-                inter.add_multiplier(KeyValues::Cha, boost, self.display_name);
+                inter.add_multiplier(KeyValues::Cha, boost, self.name);
+            }
+            BlessingTypes::HermesSwiftness => {
+                let boost = get_multiplier(blessing_state.level);
+                inter.add_multiplier(KeyValues::Dex, boost, self.name);
+            }
+            BlessingTypes::HadesBargain => {
+                let boost = get_multiplier(blessing_state.level);
+                inter.add_multiplier(KeyValues::Coins, boost, self.name);
             }
         }
     }
@@ -81,6 +90,22 @@ pub fn translate_blessing(blessing: BlessingTypes) -> Blessing {
             required_tier: 0,
             icon: IconType::Cha.into(),
         },
+        BlessingTypes::HermesSwiftness => Blessing {
+            name: blessing,
+            base_purchasing_cost: 100.0,
+            description: "Only for those who follow Hermes",
+            display_name: "Hermes Swiftness",
+            required_tier: 0,
+            icon: IconType::Dex.into(),
+        },
+        BlessingTypes::HadesBargain => Blessing {
+            name: blessing,
+            base_purchasing_cost: 1000.0,
+            description: "Only for those who follow Hades",
+            display_name: "Hades Bargain",
+            required_tier: 5,
+            icon: IconType::Coin.into(),
+        },
     }
 }
 
@@ -109,13 +134,20 @@ pub fn calculate_effect_description(input_blessing: BlessingTypes, game: &Game)
         BlessingTypes::AfroditesCharm => {
             format!("Charisma XP: {:.2}x", get_multiplier(blessing_state.level))
         }
+        BlessingTypes::HermesSwiftness => {
+            format!("Dexterity XP: {:.2}x", get_multiplier(blessing_state.level))
+        }
+        BlessingTypes::HadesBargain => {
+            format!("Coins: {:.2}x", get_multiplier(blessing_state.level))
+        }
     }
 }
 
 pub fn calculate_blessing_next_level_cost(input_blessing: BlessingTypes, game: &Game) -> f64 {
     let blessing = &game.state.blessings[input_blessing as usize];
     let blessing_world = &game.world.blessings[input_blessing as usize];
-    blessing_world.base_purchasing_cost * 1.5f64.powi(blessing.level as i32)
+    let growth = blessing_cost_growth(input_blessing);
+    blessing_world.base_purchasing_cost * growth.powi(blessing.level as i32)
 }
 
 pub fn should_unlock_blessing(input_blessing: BlessingTypes, game: &Game) -> bool {
@@ -127,12 +159,13 @@ pub fn should_unlock_blessing(input_blessing: BlessingTypes, game: &Game) -> boo
     if blessing.next_level_cost > game.state.items.divine_favor {
         return false;
     }
-    true
+    deity_allows_blessing(input_blessing, game)
 }
 
 pub fn should_be_visible_blessing(input_blessing: BlessingTypes, game: &Game) -> bool {
     let blessing = &game.world.blessings[input_blessing as usize];
     blessing.required_tier <= game.state.rebirth_stats.tier
+        && deity_allows_blessing(input_blessing, game)
 }
 
 pub fn get_blessings() -> [Blessing; BLESSING_SIZE] {
@@ -4,6 +4,7 @@ use crate::game::Game;
 use crate::icon::{Icon, IconType};
 use crate::input::boost_item::{BoostItemTypes, BOOST_ITEM_SIZE};
 use crate::input::work::WorkTypes;
+use crate::world_content::work::CRAFTING_WORKS;
 use serde::Serialize;
 use std::mem::{self, MaybeUninit};
 use strum::IntoEnumIterator;
@@ -23,157 +24,177 @@ impl Gain for BoostItem {
     fn gain(&self, inter: &mut IntermediateState) {
         match self.name {
             BoostItemTypes::Book => {
-                inter.add_multiplier(KeyValues::Int, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Int, 1.5, self.name);
             }
             BoostItemTypes::Shoe1 => {
-                inter.add_multiplier(KeyValues::Con, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Con, 1.5, self.name);
             }
             BoostItemTypes::Clothes1 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.5, self.name);
             }
             BoostItemTypes::IronPickAxe => {
-                inter.add_multiplier(KeyValues::Mines, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mines, 2.0, self.name);
             }
             BoostItemTypes::MiningGear => {
-                inter.add_multiplier(KeyValues::Mines, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mines, 2.0, self.name);
             }
             BoostItemTypes::ExpertMiningGear => {
-                inter.add_multiplier(KeyValues::Mines, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mines, 2.0, self.name);
             }
             BoostItemTypes::IronAxe => {
-                inter.add_multiplier(KeyValues::Woodcutter, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Woodcutter, 2.0, self.name);
             }
             BoostItemTypes::Book2 => {
-                inter.add_multiplier(KeyValues::Int, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Int, 1.5, self.name);
             }
             BoostItemTypes::Shoe2 => {
-                inter.add_multiplier(KeyValues::Con, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Con, 1.5, self.name);
             }
             BoostItemTypes::Clothes2 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             BoostItemTypes::Book3 => {
-                inter.add_multiplier(KeyValues::Int, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Int, 1.5, self.name);
             }
             BoostItemTypes::Book4 => {
-                inter.add_multiplier(KeyValues::Int, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Int, 2.0, self.name);
             }
             BoostItemTypes::Book5 => {
-                inter.add_multiplier(KeyValues::Int, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Int, 1.5, self.name);
             }
             BoostItemTypes::Book6 => {
-                inter.add_multiplier(KeyValues::Int, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Int, 1.5, self.name);
             }
             BoostItemTypes::Shoe3 => {
-                inter.add_multiplier(KeyValues::Con, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Con, 1.5, self.name);
             }
             BoostItemTypes::Shoe4 => {
-                inter.add_multiplier(KeyValues::Con, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Con, 2.0, self.name);
             }
             BoostItemTypes::Shoe5 => {
-                inter.add_multiplier(KeyValues::Con, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Con, 1.5, self.name);
             }
             BoostItemTypes::Shoe6 => {
-                inter.add_multiplier(KeyValues::Con, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Con, 1.5, self.name);
             }
             BoostItemTypes::FishingGear => {
-                inter.add_multiplier(KeyValues::Fisherman, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Fisherman, 1.5, self.name);
             }
             BoostItemTypes::Clothes3 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             BoostItemTypes::Clothes4 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             BoostItemTypes::Clothes5 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             BoostItemTypes::Clothes6 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             BoostItemTypes::Leach => {
-                inter.add_base(KeyValues::Health, 1.0, self.display_name);
+                inter.add_base(KeyValues::Health, 1.0, self.name);
             }
             BoostItemTypes::PitchFork => {
-                inter.add_multiplier(KeyValues::Farmer, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Farmer, 2.0, self.name);
             }
             BoostItemTypes::HealthKit => {
-                inter.add_base(KeyValues::Health, 2.0, self.display_name);
+                inter.add_base(KeyValues::Health, 2.0, self.name);
             }
             BoostItemTypes::Dumbell => {
-                inter.add_multiplier(KeyValues::Str, 4.0, self.display_name);
+                inter.add_multiplier(KeyValues::Str, 4.0, self.name);
             }
             BoostItemTypes::Dumbell2 => {
-                inter.add_multiplier(KeyValues::Str, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Str, 2.0, self.name);
             }
             BoostItemTypes::Dumbell3 => {
-                inter.add_multiplier(KeyValues::Str, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Str, 2.0, self.name);
             }
             BoostItemTypes::Dumbell4 => {
-                inter.add_multiplier(KeyValues::Str, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Str, 2.0, self.name);
             }
             BoostItemTypes::Dumbell5 => {
-                inter.add_multiplier(KeyValues::Str, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Str, 2.0, self.name);
             }
             BoostItemTypes::Flower1 => {
-                inter.add_multiplier(KeyValues::Cha, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Cha, 2.0, self.name);
             }
             BoostItemTypes::Flower2 => {
-                inter.add_multiplier(KeyValues::Cha, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Cha, 2.0, self.name);
             }
             BoostItemTypes::Flower3 => {
-                inter.add_multiplier(KeyValues::Cha, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Cha, 2.0, self.name);
             }
             BoostItemTypes::Flower4 => {
-                inter.add_multiplier(KeyValues::Cha, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Cha, 2.0, self.name);
             }
             BoostItemTypes::Meditation1 => {
-                inter.add_multiplier(KeyValues::Mindful, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mindful, 2.0, self.name);
             }
             BoostItemTypes::Meditation2 => {
-                inter.add_multiplier(KeyValues::Mindful, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mindful, 2.0, self.name);
             }
             BoostItemTypes::Meditation3 => {
-                inter.add_multiplier(KeyValues::Mindful, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mindful, 2.0, self.name);
             }
             BoostItemTypes::Meditation4 => {
-                inter.add_multiplier(KeyValues::Mindful, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mindful, 2.0, self.name);
             }
             BoostItemTypes::Burial1 => {
-                inter.add_multiplier(KeyValues::Coins, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 2.0, self.name);
             }
             BoostItemTypes::Burial2 => {
-                inter.add_multiplier(KeyValues::Coins, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 2.0, self.name);
             }
             BoostItemTypes::Burial3 => {
-                inter.add_multiplier(KeyValues::Coins, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 2.0, self.name);
             }
             BoostItemTypes::Burial4 => {
-                inter.add_multiplier(KeyValues::Coins, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 2.0, self.name);
             }
             BoostItemTypes::BribeOfficial1 => {
-                inter.add_multiplier(KeyValues::Money, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Money, 1.3, self.name);
             }
             BoostItemTypes::BribeOfficial2 => {
-                inter.add_multiplier(KeyValues::Money, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Money, 1.3, self.name);
             }
             BoostItemTypes::BribeOfficial3 => {
-                inter.add_multiplier(KeyValues::Money, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Money, 1.3, self.name);
             }
             BoostItemTypes::Tactics1 => {
-                inter.add_multiplier(KeyValues::Tactics, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::Tactics, 2.0, self.name);
             }
             BoostItemTypes::Tactics2 => {
-                inter.add_multiplier(KeyValues::Tactics, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Tactics, 1.5, self.name);
             }
             BoostItemTypes::Tactics3 => {
-                inter.add_multiplier(KeyValues::Tactics, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Tactics, 1.5, self.name);
             }
             BoostItemTypes::Tactics4 => {
-                inter.add_multiplier(KeyValues::Tactics, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Tactics, 1.5, self.name);
             }
             BoostItemTypes::Tactics5 => {
-                inter.add_multiplier(KeyValues::Tactics, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Tactics, 1.5, self.name);
+            }
+            BoostItemTypes::Quill => {
+                inter.add_multiplier(KeyValues::IntellectualXp, 2.0, self.name);
+            }
+            BoostItemTypes::Abacus => {
+                inter.add_multiplier(KeyValues::IntellectualXp, 1.5, self.name);
+            }
+            BoostItemTypes::Scrolls => {
+                inter.add_multiplier(KeyValues::IntellectualXp, 2.0, self.name);
+            }
+            BoostItemTypes::JugglingBalls => {
+                inter.add_multiplier(KeyValues::Dex, 2.0, self.name);
+            }
+            BoostItemTypes::FineTools => {
+                for work in CRAFTING_WORKS {
+                    inter.add_multiplier(work.into(), 1.5, self.name);
+                }
+            }
+            BoostItemTypes::BalanceBeam => {
+                inter.add_multiplier(KeyValues::SoldierXp, 1.5, self.name);
             }
         }
     }
@@ -612,6 +633,60 @@ pub fn translate_boost_item(item_type: BoostItemTypes) -> BoostItem {
             required_tier: 4,
             icon: IconType::Tactics.into(),
         },
+        BoostItemTypes::Quill => BoostItem {
+            name: item_type,
+            purchasing_cost: 50_000.0,
+            description: "A scholar is nothing without a good quill",
+            effect_description: "2x Intellectual XP",
+            display_name: "Quill",
+            required_tier: 5,
+            icon: IconType::Int.into(),
+        },
+        BoostItemTypes::Abacus => BoostItem {
+            name: item_type,
+            purchasing_cost: 5.0e6,
+            description: "Counting beads, for those who can count higher than their fingers",
+            effect_description: "1.5x Intellectual XP",
+            display_name: "Abacus",
+            required_tier: 5,
+            icon: IconType::Int.into(),
+        },
+        BoostItemTypes::Scrolls => BoostItem {
+            name: item_type,
+            purchasing_cost: 100.0e6,
+            description: "Copies of the great works, some of them even complete",
+            effect_description: "2x Intellectual XP",
+            display_name: "Scrolls",
+            required_tier: 6,
+            icon: IconType::Int.into(),
+        },
+        BoostItemTypes::JugglingBalls => BoostItem {
+            name: item_type,
+            purchasing_cost: 10.0e6,
+            description: "Three balls, sometimes even in the air at once",
+            effect_description: "2x Dexterity XP",
+            display_name: "Juggling Balls",
+            required_tier: 7,
+            icon: IconType::Dex.into(),
+        },
+        BoostItemTypes::FineTools => BoostItem {
+            name: item_type,
+            purchasing_cost: 50.0e6,
+            description: "Steady hands deserve sharp chisels",
+            effect_description: "1.5x income from crafting jobs",
+            display_name: "Fine Tools",
+            required_tier: 7,
+            icon: IconType::Dex.into(),
+        },
+        BoostItemTypes::BalanceBeam => BoostItem {
+            name: item_type,
+            purchasing_cost: 200.0e6,
+            description: "A rider who keeps his balance keeps his horse",
+            effect_description: "1.5x Soldier XP",
+            display_name: "Balance Beam",
+            required_tier: 7,
+            icon: IconType::Dex.into(),
+        },
 
         BoostItemTypes::Meditation1 => BoostItem {
             name: item_type,
@@ -0,0 +1,120 @@
+use crate::engine::value_keys::KeyValues;
+use crate::events::GameEventType;
+use crate::game::Game;
+use crate::input::challenge::{ChallengeTypes, CHALLENGE_SIZE};
+use crate::input::housing::HousingTypes;
+use crate::input::work::{WorkCategoryTypes, WorkTypes};
+use crate::WORLD;
+use serde::Serialize;
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize)]
+pub struct Challenge {
+    pub name: ChallengeTypes,
+    pub description: &'static str,
+    pub effect_description: &'static str,
+    pub display_name: &'static str,
+    pub required_tier: u32,
+    // Coins to gain in one life under the challenge to complete it
+    pub goal_coins: f64,
+}
+
+impl Challenge {
+    // The reward, kept for good once the challenge is completed
+    pub fn get_challenge_gains(&self, game: &mut Game) {
+        if !game.state.rebirth_stats.challenges[self.name as usize].is_completed {
+            return;
+        }
+        let inter = &mut game.intermediate_state;
+        match self.name {
+            ChallengeTypes::LaborOnly => {
+                inter.add_multiplier(KeyValues::LaborXp, 2.0, self.name);
+            }
+            ChallengeTypes::StoneFloor => {
+                inter.add_base(KeyValues::Health, 2.0, self.name);
+            }
+            ChallengeTypes::NoBoostItems => {
+                inter.add_multiplier(KeyValues::Stats, 1.25, self.name);
+            }
+        }
+    }
+}
+
+pub fn translate_challenge(challenge: ChallengeTypes) -> Challenge {
+    match challenge {
+        ChallengeTypes::LaborOnly => Challenge {
+            name: challenge,
+            description: "Honest work only, no soldiers, scholars or priests",
+            effect_description: "Labor XP 2x",
+            display_name: "Labor Only",
+            required_tier: 2,
+            goal_coins: 50.0,
+        },
+        ChallengeTypes::StoneFloor => Challenge {
+            name: challenge,
+            description: "Sleep on the stone floor your whole life",
+            effect_description: "+2 Health",
+            display_name: "Stone Floor",
+            required_tier: 2,
+            goal_coins: 50.0,
+        },
+        ChallengeTypes::NoBoostItems => Challenge {
+            name: challenge,
+            description: "Live without any of the items",
+            effect_description: "Stat XP 1.25x",
+            display_name: "Ascetic",
+            required_tier: 3,
+            goal_coins: 500.0,
+        },
+    }
+}
+
+pub fn get_challenges() -> [Challenge; CHALLENGE_SIZE] {
+    let mut challenges: [MaybeUninit<Challenge>; CHALLENGE_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in ChallengeTypes::iter() {
+        challenges[name as usize].write(translate_challenge(name));
+    }
+    unsafe { mem::transmute(challenges) }
+}
+
+pub fn can_start_challenge(challenge: ChallengeTypes, game: &Game) -> bool {
+    let challenge = &WORLD.challenges[challenge as usize];
+    game.state.rebirth_stats.tier >= challenge.required_tier
+}
+
+pub fn challenge_allows_work(work: WorkTypes, game: &Game) -> bool {
+    match game.state.life_stats.challenge {
+        Some(ChallengeTypes::LaborOnly) => {
+            WORLD.get_work(work).work_type == WorkCategoryTypes::Labor
+        }
+        _ => true,
+    }
+}
+
+pub fn challenge_allows_housing(housing: HousingTypes, game: &Game) -> bool {
+    match game.state.life_stats.challenge {
+        Some(ChallengeTypes::StoneFloor) => housing == HousingTypes::StoneFloor,
+        _ => true,
+    }
+}
+
+pub fn challenge_allows_boost_items(game: &Game) -> bool {
+    game.state.life_stats.challenge != Some(ChallengeTypes::NoBoostItems)
+}
+
+// Called at death with the coins the life gained
+pub fn check_challenge_completion(game: &mut Game, coins_gain: f64) {
+    let challenge = match game.state.life_stats.challenge {
+        Some(challenge) => challenge,
+        None => return,
+    };
+    let goal_coins = WORLD.challenges[challenge as usize].goal_coins;
+    let challenge_state = &mut game.state.rebirth_stats.challenges[challenge as usize];
+    challenge_state.best_coins_gain = challenge_state.best_coins_gain.max(coins_gain);
+    if !challenge_state.is_completed && coins_gain >= goal_coins {
+        challenge_state.is_completed = true;
+        game.push_event(GameEventType::ChallengeCompleted { challenge });
+    }
+}
@@ -0,0 +1,139 @@
+use crate::engine::value_keys::KeyValues;
+use crate::game::Game;
+use crate::input::blessing::BlessingTypes;
+use crate::input::deity::{DeityTypes, DEITY_SIZE};
+use crate::WORLD;
+use serde::Serialize;
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize)]
+pub struct Deity {
+    pub name: DeityTypes,
+    pub description: &'static str,
+    pub drawback_description: &'static str,
+    pub display_name: &'static str,
+    // The blessing this deity grants, the only one that can be bought under it
+    pub blessing: BlessingTypes,
+    // How much each level of the blessing costs more than the last
+    pub cost_growth: f64,
+}
+
+impl Deity {
+    // The drawback of the patron of this life
+    pub fn get_deity_gains(&self, game: &mut Game) {
+        if game.input.deity != Some(self.name) {
+            return;
+        }
+        let inter = &mut game.intermediate_state;
+        match self.name {
+            DeityTypes::Herucles => {
+                inter.add_multiplier(KeyValues::Int, 0.8, self.name);
+            }
+            DeityTypes::Athena => {
+                inter.add_multiplier(KeyValues::Str, 0.8, self.name);
+            }
+            DeityTypes::Afrodite => {
+                inter.add_multiplier(KeyValues::Con, 0.8, self.name);
+            }
+            DeityTypes::Poseidon => {
+                inter.add_multiplier(KeyValues::Happiness, 0.9, self.name);
+            }
+            DeityTypes::Hermes => {
+                inter.add_multiplier(KeyValues::Faith, 0.8, self.name);
+            }
+            DeityTypes::Hades => {
+                inter.add_base(KeyValues::Health, -1.0, self.name);
+            }
+        }
+    }
+}
+
+pub fn translate_deity(deity: DeityTypes) -> Deity {
+    match deity {
+        DeityTypes::Herucles => Deity {
+            name: deity,
+            description: "Strength is all a hero needs",
+            drawback_description: "Intelligence XP 0.8x",
+            display_name: "Herucles",
+            blessing: BlessingTypes::HeruclesStrength,
+            cost_growth: 1.3,
+        },
+        DeityTypes::Athena => Deity {
+            name: deity,
+            description: "Wisdom over brawn",
+            drawback_description: "Strength XP 0.8x",
+            display_name: "Athena",
+            blessing: BlessingTypes::AthenasWisdom,
+            cost_growth: 1.3,
+        },
+        DeityTypes::Afrodite => Deity {
+            name: deity,
+            description: "Beauty fades, charm does not",
+            drawback_description: "Constitution XP 0.8x",
+            display_name: "Afrodite",
+            blessing: BlessingTypes::AfroditesCharm,
+            cost_growth: 1.3,
+        },
+        DeityTypes::Poseidon => Deity {
+            name: deity,
+            description: "Weather every storm",
+            drawback_description: "Happiness 0.9x",
+            display_name: "Poseidon",
+            blessing: BlessingTypes::PoseidonsSturdiness,
+            cost_growth: 1.3,
+        },
+        DeityTypes::Hermes => Deity {
+            name: deity,
+            description: "Quick hands and a quicker tongue",
+            drawback_description: "Faith XP 0.8x",
+            display_name: "Hermes",
+            blessing: BlessingTypes::HermesSwiftness,
+            cost_growth: 1.4,
+        },
+        DeityTypes::Hades => Deity {
+            name: deity,
+            description: "Every soul comes to him in the end",
+            drawback_description: "-1 Health",
+            display_name: "Hades",
+            blessing: BlessingTypes::HadesBargain,
+            cost_growth: 2.0,
+        },
+    }
+}
+
+pub fn get_deities() -> [Deity; DEITY_SIZE] {
+    let mut deities: [MaybeUninit<Deity>; DEITY_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in DeityTypes::iter() {
+        deities[name as usize].write(translate_deity(name));
+    }
+    unsafe { mem::transmute(deities) }
+}
+
+// The deity whose blessing it is
+pub fn deity_of_blessing(blessing: BlessingTypes) -> DeityTypes {
+    WORLD
+        .deities
+        .iter()
+        .find(|deity| deity.blessing == blessing)
+        .expect("every blessing has a deity")
+        .name
+}
+
+// Only the patron of this life blesses, without one there are no blessings
+pub fn deity_allows_blessing(blessing: BlessingTypes, game: &Game) -> bool {
+    game.input.deity == Some(deity_of_blessing(blessing))
+}
+
+pub fn blessing_cost_growth(blessing: BlessingTypes) -> f64 {
+    WORLD.deities[deity_of_blessing(blessing) as usize].cost_growth
+}
+
+// The patron can only be picked once per life
+pub fn can_choose_deity(deity: DeityTypes, game: &Game) -> bool {
+    let blessing = &WORLD.blessings[WORLD.deities[deity as usize].blessing as usize];
+    game.input.deity.is_none()
+        && game.state.rebirth_stats.unlocks.has_faith
+        && game.state.rebirth_stats.tier >= blessing.required_tier
+}
@@ -14,16 +14,14 @@ pub struct Housing {
     pub display_name: &'static str,
     pub required_money: f64,
     pub happiness_factor: f64,
+    // Health base, on the scale of the health costs of the jobs
     pub health_effect: f64,
 }
 
 impl Gain for Housing {
     fn gain(&self, intermediate: &mut IntermediateState) {
-        intermediate.add_multiplier(
-            KeyValues::Happiness,
-            self.happiness_factor,
-            self.display_name,
-        );
+        intermediate.add_multiplier(KeyValues::Happiness, self.happiness_factor, self.name);
+        intermediate.add_base(KeyValues::Health, self.health_effect, self.name);
     }
 }
 
@@ -36,7 +34,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "Stone Floor",
             required_money: 0.0,
             happiness_factor: 1.0,
-            health_effect: -10.0,
+            health_effect: -2.0,
         },
         HousingTypes::ComfortableSpot => Housing {
             name: housing,
@@ -45,7 +43,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "A Comforable Spot",
             required_money: 0.0,
             happiness_factor: 1.5,
-            health_effect: -3.0,
+            health_effect: -0.6,
         },
         HousingTypes::FilthyBarracks => Housing {
             name: housing,
@@ -54,7 +52,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "Filthy Barracks",
             required_money: 100.0,
             happiness_factor: 2.0,
-            health_effect: -1.0,
+            health_effect: -0.2,
         },
         HousingTypes::CrampedBarracks => Housing {
             name: housing,
@@ -90,7 +88,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "Private Room",
             required_money: 64_000.0,
             happiness_factor: 14.0,
-            health_effect: 1.0,
+            health_effect: 0.2,
         },
         HousingTypes::TinyApartment => Housing {
             name: housing,
@@ -99,7 +97,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "Tiny Apartment",
             required_money: 256_000.0,
             happiness_factor: 20.0,
-            health_effect: 2.0,
+            health_effect: 0.4,
         },
         HousingTypes::SmallApartment => Housing {
             name: housing,
@@ -108,7 +106,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "Small Apartment",
             required_money: 1.0e6,
             happiness_factor: 30.0,
-            health_effect: 3.0,
+            health_effect: 0.6,
         },
         HousingTypes::Apartment => Housing {
             name: housing,
@@ -117,7 +115,7 @@ pub fn translate_housing(housing: HousingTypes) -> Housing {
             display_name: "Apartment",
             required_money: 4.0e6,
             happiness_factor: 45.0,
-            health_effect: 4.0,
+            health_effect: 0.8,
         },
     }
 }
@@ -0,0 +1,161 @@
+use crate::engine::value_keys::KeyValues;
+use crate::game::Game;
+use crate::icon::{Icon, IconType};
+use crate::input::karma_upgrade::{KarmaUpgradeTypes, KARMA_UPGRADE_SIZE};
+use crate::input::stat::StatTypes;
+use crate::input::work::WorkCategoryTypes;
+use crate::state::state_container::StateContainer;
+use crate::WORLD;
+use serde::Serialize;
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+#[derive(Serialize)]
+pub struct KarmaUpgrade {
+    pub name: KarmaUpgradeTypes,
+    pub purchasing_cost: f64,
+    pub description: &'static str,
+    pub display_name: &'static str,
+    pub required_tier: u32,
+    pub effect_description: &'static str,
+    pub icon: Icon,
+}
+
+// Karma is earned for a pious life: faith, priestly work and blessings
+pub fn calculate_karma_gain(state: &StateContainer) -> f64 {
+    let faith = state.stats[StatTypes::Faith as usize].level;
+    let priest_levels: u32 = state
+        .works
+        .iter()
+        .filter(|work| WORLD.get_work(work.name).work_type == WorkCategoryTypes::Priest)
+        .map(|work| work.level)
+        .sum();
+    let blessing_levels: u32 = state.blessings.iter().map(|b| b.level).sum();
+    faith / 10.0 + priest_levels as f64 / 5.0 + blessing_levels as f64 / 10.0
+}
+
+impl KarmaUpgrade {
+    pub fn get_upgrade_gains(&self, game: &mut Game) {
+        let upgrade_state = &game.state.rebirth_stats.karma_upgrades[self.name as usize];
+        if !upgrade_state.is_purchased {
+            return;
+        }
+
+        let inter = &mut game.intermediate_state;
+        match self.name {
+            KarmaUpgradeTypes::InnerPeace => {
+                inter.add_multiplier(KeyValues::Happiness, 1.5, self.name);
+            }
+            KarmaUpgradeTypes::Devotion => {
+                inter.add_multiplier(KeyValues::PriestXp, 2.0, self.name);
+            }
+            KarmaUpgradeTypes::Pilgrimage => {
+                inter.add_multiplier(KeyValues::Faith, 2.0, self.name);
+            }
+            KarmaUpgradeTypes::GoodDeeds => {
+                inter.add_multiplier(KeyValues::DivineFavor, 1.5, self.name);
+            }
+            KarmaUpgradeTypes::Virtue => {
+                inter.add_multiplier(KeyValues::Karma, 1.5, self.name);
+            }
+            KarmaUpgradeTypes::Enlightenment => {
+                inter.add_multiplier(KeyValues::Skills, 1.5, self.name);
+            }
+            KarmaUpgradeTypes::Serenity => {
+                inter.add_multiplier(KeyValues::Health, 1.5, self.name);
+            }
+        }
+    }
+}
+
+pub fn translate_karma_upgrade(karma_upgrade: KarmaUpgradeTypes) -> KarmaUpgrade {
+    match karma_upgrade {
+        KarmaUpgradeTypes::InnerPeace => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 1.0,
+            description: "A clear conscience makes for a lighter heart",
+            display_name: "Inner Peace",
+            effect_description: "Happiness 1.5x",
+            required_tier: 4,
+            icon: IconType::Happiness.into(),
+        },
+        KarmaUpgradeTypes::Devotion => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 3.0,
+            description: "The rites come easier every time",
+            display_name: "Devotion",
+            effect_description: "Priest XP 2x",
+            required_tier: 4,
+            icon: IconType::Priest.into(),
+        },
+        KarmaUpgradeTypes::Pilgrimage => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 5.0,
+            description: "You remember the long road to the temple",
+            display_name: "Pilgrimage",
+            effect_description: "Faith XP 2x",
+            required_tier: 4,
+            icon: IconType::Faith.into(),
+        },
+        KarmaUpgradeTypes::GoodDeeds => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 10.0,
+            description: "The gods have not forgotten your kindness",
+            display_name: "Good Deeds",
+            effect_description: "Divine Favor 1.5x",
+            required_tier: 4,
+            icon: IconType::DivineFavor.into(),
+        },
+        KarmaUpgradeTypes::Virtue => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 20.0,
+            description: "Good begets good",
+            display_name: "Virtue",
+            effect_description: "Karma 1.5x",
+            required_tier: 4,
+            icon: IconType::Faith.into(),
+        },
+        KarmaUpgradeTypes::Enlightenment => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 40.0,
+            description: "Every life teaches you something new",
+            display_name: "Enlightenment",
+            effect_description: "Skill XP 1.5x",
+            required_tier: 4,
+            icon: IconType::Mindful.into(),
+        },
+        KarmaUpgradeTypes::Serenity => KarmaUpgrade {
+            name: karma_upgrade,
+            purchasing_cost: 80.0,
+            description: "A calm soul keeps the body well",
+            display_name: "Serenity",
+            effect_description: "Health 1.5x",
+            required_tier: 4,
+            icon: IconType::Health.into(),
+        },
+    }
+}
+
+pub fn should_unlock_karma_upgrade(input_karma_upgrade: KarmaUpgradeTypes, game: &Game) -> bool {
+    let karma_upgrade = &game.world.karma_upgrades[input_karma_upgrade as usize];
+    should_be_visible_karma_upgrade(input_karma_upgrade, game)
+        && karma_upgrade.purchasing_cost <= game.state.rebirth_stats.karma
+}
+
+pub fn should_be_visible_karma_upgrade(
+    input_karma_upgrade: KarmaUpgradeTypes,
+    game: &Game,
+) -> bool {
+    let karma_upgrade = &game.world.karma_upgrades[input_karma_upgrade as usize];
+    let rebirth_stats = &game.state.rebirth_stats;
+    karma_upgrade.required_tier <= rebirth_stats.tier || rebirth_stats.karma > 0.0
+}
+
+pub fn get_karma_upgrades() -> [KarmaUpgrade; KARMA_UPGRADE_SIZE] {
+    let mut karma_upgrades: [MaybeUninit<KarmaUpgrade>; KARMA_UPGRADE_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in KarmaUpgradeTypes::iter() {
+        karma_upgrades[name as usize].write(translate_karma_upgrade(name));
+    }
+    unsafe { mem::transmute(karma_upgrades) }
+}
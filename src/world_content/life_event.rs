@@ -0,0 +1,115 @@
+use crate::engine::intermediate_state::{Gain, IntermediateState};
+use crate::engine::value_keys::KeyValues;
+use crate::input::life_event::{LifeEventTypes, LIFE_EVENT_SIZE};
+use crate::input::work::WorkTypes;
+use crate::state::rng::Rng;
+use crate::WORLD;
+use serde::Serialize;
+use std::mem::{self, MaybeUninit};
+use strum::IntoEnumIterator;
+
+// Ticks between two rolls for a life event, 30 seconds
+pub const LIFE_EVENT_INTERVAL: u32 = 900;
+// Lower tiers keep the deterministic lives the early game is balanced around
+pub const LIFE_EVENT_TIER: u32 = 6;
+
+#[derive(Serialize, Clone)]
+pub struct LifeEvent {
+    pub name: LifeEventTypes,
+    pub description: &'static str,
+    pub effect_description: &'static str,
+    pub display_name: &'static str,
+    // Chance to happen on each roll
+    pub chance: f64,
+    // How many ticks the effect lasts
+    pub duration: u32,
+    pub required_tier: u32,
+}
+
+impl Gain for LifeEvent {
+    fn gain(&self, intermediate: &mut IntermediateState) {
+        match self.name {
+            LifeEventTypes::Windfall => {
+                for work in WorkTypes::iter() {
+                    intermediate.add_multiplier(work.into(), 2.0, self.name);
+                }
+            }
+            LifeEventTypes::Robbery => {
+                for work in WorkTypes::iter() {
+                    intermediate.add_multiplier(work.into(), 0.5, self.name);
+                }
+            }
+            LifeEventTypes::Illness => {
+                intermediate.add_base(KeyValues::Health, -3.0, self.name);
+            }
+            LifeEventTypes::Festival => {
+                intermediate.add_multiplier(KeyValues::Happiness, 1.5, self.name);
+            }
+        }
+    }
+}
+
+pub fn translate_life_event(life_event: LifeEventTypes) -> LifeEvent {
+    match life_event {
+        LifeEventTypes::Windfall => LifeEvent {
+            name: life_event,
+            description: "A distant relative remembered you in their will",
+            effect_description: "Doubles income",
+            display_name: "Windfall",
+            chance: 0.08,
+            duration: 900,
+            required_tier: LIFE_EVENT_TIER,
+        },
+        LifeEventTypes::Robbery => LifeEvent {
+            name: life_event,
+            description: "Thieves took your tools, you make do without them",
+            effect_description: "Halves income",
+            display_name: "Robbery",
+            chance: 0.06,
+            duration: 900,
+            required_tier: LIFE_EVENT_TIER,
+        },
+        LifeEventTypes::Illness => LifeEvent {
+            name: life_event,
+            description: "A fever is going around",
+            effect_description: "-3 Health",
+            display_name: "Illness",
+            chance: 0.06,
+            duration: 1800,
+            required_tier: LIFE_EVENT_TIER,
+        },
+        LifeEventTypes::Festival => LifeEvent {
+            name: life_event,
+            description: "The whole town is out in the streets",
+            effect_description: "Multiplies Happiness by 1.5",
+            display_name: "Festival",
+            chance: 0.08,
+            duration: 900,
+            required_tier: LIFE_EVENT_TIER,
+        },
+    }
+}
+
+pub fn get_life_events() -> [LifeEvent; LIFE_EVENT_SIZE] {
+    let mut life_events: [MaybeUninit<LifeEvent>; LIFE_EVENT_SIZE] =
+        unsafe { MaybeUninit::uninit().assume_init() };
+    for name in LifeEventTypes::iter() {
+        life_events[name as usize].write(translate_life_event(name));
+    }
+    unsafe { mem::transmute(life_events) }
+}
+
+// Draws exactly one number, so the rolls of a life only depend on the seed
+pub fn roll_life_event(rng: &mut Rng, tier: u32) -> Option<LifeEventTypes> {
+    let mut roll = rng.next_f64();
+    for life_event in WORLD.life_events.iter() {
+        if life_event.required_tier > tier {
+            continue;
+        }
+        if roll < life_event.chance {
+            return Some(life_event.name);
+        }
+        roll -= life_event.chance;
+    }
+    None
+}
@@ -1,7 +1,12 @@
+pub mod achievement;
 pub mod activity;
 pub mod blessing;
 pub mod boost_item;
+pub mod challenge;
+pub mod deity;
 pub mod housing;
+pub mod karma_upgrade;
+pub mod life_event;
 pub mod rebirth_upgrade;
 pub mod settings;
 pub mod skill;
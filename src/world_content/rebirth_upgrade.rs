@@ -110,55 +110,82 @@ impl RebirthUpgrade {
         let inter = &mut game.intermediate_state;
         match self.name {
             RebirthUpgradeTypes::GemKnowledge => {
-                inter.add_multiplier(KeyValues::Mines, 100.0, self.display_name);
+                inter.add_multiplier(KeyValues::Mines, 100.0, self.name);
             }
             RebirthUpgradeTypes::AcceptingDeath => {
-                inter.add_multiplier(KeyValues::Happiness, 1.7, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.7, self.name);
             }
             RebirthUpgradeTypes::AcceptingDeath2 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             RebirthUpgradeTypes::AcceptingDeath3 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.3, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.3, self.name);
             }
             RebirthUpgradeTypes::AcceptingDeath4 => {
-                inter.add_multiplier(KeyValues::Happiness, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::Happiness, 1.5, self.name);
             }
             RebirthUpgradeTypes::Privilege1 => {
-                inter.add_multiplier(KeyValues::Money, 1.2, self.display_name);
+                inter.add_multiplier(KeyValues::Money, 1.2, self.name);
             }
             RebirthUpgradeTypes::Privilege2 => {
-                inter.add_multiplier(KeyValues::Money, 1.2, self.display_name);
+                inter.add_multiplier(KeyValues::Money, 1.2, self.name);
             }
             RebirthUpgradeTypes::Privilege3 => {
-                inter.add_multiplier(KeyValues::Money, 1.2, self.display_name);
+                inter.add_multiplier(KeyValues::Money, 1.2, self.name);
+            }
+            RebirthUpgradeTypes::Privilege4 => {
+                inter.add_multiplier(KeyValues::Money, 1.2, self.name);
             }
             RebirthUpgradeTypes::LaborXp1 => {
-                inter.add_multiplier(KeyValues::LaborXp, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::LaborXp, 1.5, self.name);
             }
             RebirthUpgradeTypes::LaborXp2 => {
-                inter.add_multiplier(KeyValues::LaborXp, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::LaborXp, 1.5, self.name);
             }
             RebirthUpgradeTypes::LaborXp3 => {
-                inter.add_multiplier(KeyValues::LaborXp, 1.5, self.display_name);
+                inter.add_multiplier(KeyValues::LaborXp, 1.5, self.name);
             }
             RebirthUpgradeTypes::SoldierXp1 => {
-                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.name);
             }
             RebirthUpgradeTypes::SoldierXp2 => {
-                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.name);
             }
             RebirthUpgradeTypes::SoldierXp3 => {
-                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.display_name);
+                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.name);
+            }
+            RebirthUpgradeTypes::SoldierXp4 => {
+                inter.add_multiplier(KeyValues::SoldierXp, 2.0, self.name);
+            }
+            RebirthUpgradeTypes::IntellectualXp1 => {
+                inter.add_multiplier(KeyValues::IntellectualXp, 2.0, self.name);
+            }
+            RebirthUpgradeTypes::IntellectualXp2 => {
+                inter.add_multiplier(KeyValues::IntellectualXp, 2.0, self.name);
             }
             RebirthUpgradeTypes::BribeCharon1 => {
-                inter.add_multiplier(KeyValues::Coins, 1.2, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 1.2, self.name);
             }
             RebirthUpgradeTypes::BribeCharon2 => {
-                inter.add_multiplier(KeyValues::Coins, 1.2, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 1.2, self.name);
             }
             RebirthUpgradeTypes::BribeCharon3 => {
-                inter.add_multiplier(KeyValues::Coins, 1.2, self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 1.2, self.name);
+            }
+            RebirthUpgradeTypes::BribeCharon4 => {
+                inter.add_multiplier(KeyValues::Coins, 1.2, self.name);
+            }
+            RebirthUpgradeTypes::BorrowedTime1 => {
+                inter.add_multiplier(KeyValues::TimeFactor, 0.97, self.name);
+            }
+            RebirthUpgradeTypes::BorrowedTime2 => {
+                inter.add_multiplier(KeyValues::TimeFactor, 0.95, self.name);
+            }
+            RebirthUpgradeTypes::BorrowedTime3 => {
+                inter.add_multiplier(KeyValues::TimeFactor, 0.9, self.name);
+            }
+            RebirthUpgradeTypes::BorrowedTime4 => {
+                inter.add_multiplier(KeyValues::TimeFactor, 0.9, self.name);
             }
             _ => (),
         }
@@ -319,6 +346,15 @@ pub fn translate_rebirth_upgrade(rebirth_upgrade: RebirthUpgradeTypes) -> Rebirt
             required_tier: 3,
             icon: IconType::Tomb.into(),
         },
+        RebirthUpgradeTypes::BorrowedTime1 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 100.0,
+            description: "Death agrees to look the other way, for a while",
+            display_name: "Borrowed Time 1",
+            effect_description: "Aging 0.97x",
+            required_tier: 3,
+            icon: IconType::Death.into(),
+        },
         RebirthUpgradeTypes::BribeCharon2 => RebirthUpgrade {
             name: rebirth_upgrade,
             purchasing_cost: 300.0,
@@ -394,6 +430,15 @@ pub fn translate_rebirth_upgrade(rebirth_upgrade: RebirthUpgradeTypes) -> Rebirt
             required_tier: 4,
             icon: IconType::Death.into(),
         },
+        RebirthUpgradeTypes::BorrowedTime2 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 1_000.0,
+            description: "The sand in your hourglass falls a little slower",
+            display_name: "Borrowed Time 2",
+            effect_description: "Aging 0.95x",
+            required_tier: 4,
+            icon: IconType::Death.into(),
+        },
         RebirthUpgradeTypes::BribeCharon3 => RebirthUpgrade {
             name: rebirth_upgrade,
             purchasing_cost: 3_000.0,
@@ -468,6 +513,24 @@ pub fn translate_rebirth_upgrade(rebirth_upgrade: RebirthUpgradeTypes) -> Rebirt
         },
 
         // TIER 5 - 50 000
+        RebirthUpgradeTypes::IntellectualXp1 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 20_000.0,
+            description: "You remember the lessons of your past lives",
+            display_name: "Intellectual Experience",
+            effect_description: "Intellectual job XP 2x",
+            required_tier: 5,
+            icon: IconType::Int.into(),
+        },
+        RebirthUpgradeTypes::BorrowedTime3 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 30_000.0,
+            description: "Even the gods lose track of you",
+            display_name: "Borrowed Time 3",
+            effect_description: "Aging 0.9x",
+            required_tier: 5,
+            icon: IconType::Death.into(),
+        },
         RebirthUpgradeTypes::AutoBuyBlessing => RebirthUpgrade {
             name: rebirth_upgrade,
             purchasing_cost: 6000.0,
@@ -505,6 +568,56 @@ pub fn translate_rebirth_upgrade(rebirth_upgrade: RebirthUpgradeTypes) -> Rebirt
         //     required_tier: 2,
         //     icon: IconType::Happiness.into(),
         // },
+        // TIER 10 - 5e9
+        RebirthUpgradeTypes::Privilege4 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 5.0e8,
+            description: "Your name opens doors, and purses",
+            display_name: "Noble Privilege",
+            effect_description: "Money 1.2x",
+            required_tier: 10,
+            icon: IconType::Money.into(),
+        },
+        // TIER 11 - 5e10
+        RebirthUpgradeTypes::BribeCharon4 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 5.0e9,
+            description: "Even the ferryman bows to a regent",
+            display_name: "Bribe Charon 4",
+            effect_description: "Coins 1.2x",
+            required_tier: 11,
+            icon: IconType::Coin.into(),
+        },
+        // TIER 12 - 5e11
+        RebirthUpgradeTypes::SoldierXp4 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 5.0e10,
+            description: "The royal army trains with you",
+            display_name: "Soldier Experience 4",
+            effect_description: "Soldier job XP 2x",
+            required_tier: 12,
+            icon: IconType::Soldier.into(),
+        },
+        // TIER 13 - 5e12
+        RebirthUpgradeTypes::IntellectualXp2 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 5.0e11,
+            description: "Scholars from every kingdom come to your court",
+            display_name: "Intellectual Experience 2",
+            effect_description: "Intellectual job XP 2x",
+            required_tier: 13,
+            icon: IconType::Int.into(),
+        },
+        // TIER 14 - 5e13
+        RebirthUpgradeTypes::BorrowedTime4 => RebirthUpgrade {
+            name: rebirth_upgrade,
+            purchasing_cost: 5.0e12,
+            description: "They will build temples to you, but not yet",
+            display_name: "Borrowed Time 4",
+            effect_description: "Aging 0.9x",
+            required_tier: 14,
+            icon: IconType::Death.into(),
+        },
     }
 }
 
@@ -545,6 +658,18 @@ pub fn should_be_visible_rebirth_upgrade(
             game.state.rebirth_stats.rebirth_upgrades[RebirthUpgradeTypes::StartingWealth3 as usize]
                 .is_purchased
         }
+        RebirthUpgradeTypes::BorrowedTime2 => {
+            game.state.rebirth_stats.rebirth_upgrades[RebirthUpgradeTypes::BorrowedTime1 as usize]
+                .is_purchased
+        }
+        RebirthUpgradeTypes::BorrowedTime3 => {
+            game.state.rebirth_stats.rebirth_upgrades[RebirthUpgradeTypes::BorrowedTime2 as usize]
+                .is_purchased
+        }
+        RebirthUpgradeTypes::BorrowedTime4 => {
+            game.state.rebirth_stats.rebirth_upgrades[RebirthUpgradeTypes::BorrowedTime3 as usize]
+                .is_purchased
+        }
         _ => true,
     }
 }
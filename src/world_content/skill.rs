@@ -2,10 +2,40 @@ use crate::engine::value_keys::KeyValues;
 use crate::game::Game;
 use crate::icon::{Icon, IconType};
 use crate::input::skill::{SkillTypes, SKILL_SIZE};
+use crate::input::stat::StatTypes;
+use crate::state::rebirth_stats::UnlockTypes;
 use serde::Serialize;
 use std::mem::{self, MaybeUninit};
 use strum::IntoEnumIterator;
 
+// What has to happen before a skill shows up and can be trained
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "type", content = "value")]
+pub enum SkillRequirement {
+    // One of the rebirth unlocks
+    Unlock(UnlockTypes),
+    // The stat has reached the level in this life
+    StatLevel(StatTypes, f64),
+    // The skill has reached the level in this life
+    SkillLevel(SkillTypes, f64),
+}
+
+impl SkillRequirement {
+    pub fn is_met(&self, game: &Game) -> bool {
+        match *self {
+            SkillRequirement::Unlock(unlock) => {
+                game.state.rebirth_stats.unlocks.is_unlocked(unlock)
+            }
+            SkillRequirement::StatLevel(stat, level) => {
+                game.state.stats[stat as usize].level >= level
+            }
+            SkillRequirement::SkillLevel(skill, level) => {
+                game.state.skills[skill as usize].level >= level
+            }
+        }
+    }
+}
+
 #[derive(Serialize, Clone)]
 pub struct Skill {
     pub name: SkillTypes,
@@ -13,30 +43,25 @@ pub struct Skill {
     pub effect_description: &'static str,
     pub display_name: &'static str,
     pub required_tier: u32,
+    pub requirements: &'static [SkillRequirement],
     pub xp_req_modifier: f64,
+    // Skill XP per second is this value times xp_factor
+    pub xp_source: KeyValues,
+    pub xp_factor: f64,
+    // Multiplied by 1 + level * effect_per_level
+    pub affected_value: KeyValues,
+    pub effect_per_level: f64,
     pub icon: Icon,
 }
 
 impl Skill {
     pub fn get_skills_gains(&self, game: &mut Game) {
-        let skill_state = &mut game.state.skills[self.name as usize];
-
-        match self.name {
-            SkillTypes::Mindful => {
-                game.intermediate_state.add_multiplier(
-                    KeyValues::Happiness,
-                    0.05 * skill_state.level + 1.0,
-                    self.display_name,
-                );
-            }
-            SkillTypes::Tactics => {
-                game.intermediate_state.add_multiplier(
-                    KeyValues::SoldierXp,
-                    0.05 * skill_state.level + 1.0,
-                    self.display_name,
-                );
-            }
-        }
+        let skill_state = &game.state.skills[self.name as usize];
+        game.intermediate_state.add_multiplier(
+            self.affected_value,
+            self.effect_per_level * skill_state.level + 1.0,
+            self.name,
+        );
     }
 }
 
@@ -48,7 +73,12 @@ pub fn translate_skill(skill: SkillTypes) -> Skill {
             effect_description: "Multiplies Happiness by 1 + level*0.05",
             display_name: "Mindfulness",
             required_tier: 1,
+            requirements: &[SkillRequirement::Unlock(UnlockTypes::HasMeditation)],
             xp_req_modifier: 4.0,
+            xp_source: KeyValues::Mindful,
+            xp_factor: 1.0,
+            affected_value: KeyValues::Happiness,
+            effect_per_level: 0.05,
             icon: IconType::Mindful.into(),
         },
         SkillTypes::Tactics => Skill {
@@ -57,9 +87,59 @@ pub fn translate_skill(skill: SkillTypes) -> Skill {
             effect_description: "Multiplies Soldier XP by 1 + level*0.05",
             display_name: "Military Tactics",
             required_tier: 3,
+            requirements: &[SkillRequirement::Unlock(UnlockTypes::HasMilitaryTactics)],
             xp_req_modifier: 1.0,
+            xp_source: KeyValues::Tactics,
+            xp_factor: 1.0,
+            affected_value: KeyValues::SoldierXp,
+            effect_per_level: 0.05,
             icon: IconType::Tactics.into(),
         },
+        SkillTypes::Oratory => Skill {
+            name: skill,
+            description: "Words move crowds and coin",
+            effect_description: "Multiplies Merchant XP by 1 + level*0.05",
+            display_name: "Oratory",
+            required_tier: 4,
+            requirements: &[SkillRequirement::StatLevel(StatTypes::Cha, 25.0)],
+            xp_req_modifier: 2.0,
+            xp_source: KeyValues::Cha,
+            xp_factor: 0.5,
+            affected_value: KeyValues::MerchantXp,
+            effect_per_level: 0.05,
+            icon: IconType::Cha.into(),
+        },
+        SkillTypes::Craftsmanship => Skill {
+            name: skill,
+            description: "Measure twice, cut once",
+            effect_description: "Multiplies Labor XP by 1 + level*0.03",
+            display_name: "Craftsmanship",
+            required_tier: 7,
+            requirements: &[SkillRequirement::StatLevel(StatTypes::Dex, 25.0)],
+            xp_req_modifier: 2.0,
+            xp_source: KeyValues::Dex,
+            xp_factor: 0.5,
+            affected_value: KeyValues::LaborXp,
+            effect_per_level: 0.03,
+            icon: IconType::Dex.into(),
+        },
+        SkillTypes::Theology => Skill {
+            name: skill,
+            description: "Know the gods and their ways",
+            effect_description: "Multiplies Divine Favor by 1 + level*0.05",
+            display_name: "Theology",
+            required_tier: 3,
+            requirements: &[
+                SkillRequirement::Unlock(UnlockTypes::HasFaith),
+                SkillRequirement::StatLevel(StatTypes::Faith, 10.0),
+            ],
+            xp_req_modifier: 3.0,
+            xp_source: KeyValues::Faith,
+            xp_factor: 0.5,
+            affected_value: KeyValues::DivineFavor,
+            effect_per_level: 0.05,
+            icon: IconType::Faith.into(),
+        },
     }
 }
 
@@ -68,11 +148,7 @@ pub fn should_be_visible_skill(input_skill: SkillTypes, game: &Game) -> bool {
     if game.state.rebirth_stats.tier < skill.required_tier {
         return false;
     }
-    match input_skill {
-        SkillTypes::Tactics => game.state.rebirth_stats.unlocks.has_military_tactics,
-        SkillTypes::Mindful => game.state.rebirth_stats.unlocks.has_meditation,
-        // _ => true,
-    }
+    skill.requirements.iter().all(|r| r.is_met(game))
 }
 
 pub fn get_skills() -> [Skill; SKILL_SIZE] {
@@ -2,6 +2,7 @@ use crate::engine::value_keys::KeyValues;
 use crate::game::Game;
 use crate::icon::{Icon, IconType};
 use crate::input::stat::{StatTypes, STAT_SIZE};
+use crate::world_content::work::CRAFTING_WORKS;
 use serde::Serialize;
 use std::mem::{self, MaybeUninit};
 use strum::IntoEnumIterator;
@@ -27,26 +28,26 @@ impl Stat {
         let inter = &mut game.intermediate_state;
         match self.name {
             StatTypes::Con => {
-                inter.add_multiplier(KeyValues::Health, 0.05 * level, self.display_name);
+                inter.add_multiplier(KeyValues::Health, 0.05 * level, self.name);
             }
             StatTypes::Int => {
-                inter.add_multiplier(KeyValues::Stats, 1.0 + (0.1 * level), self.display_name);
-                inter.add_multiplier(KeyValues::Skills, 1.0 + (0.1 * level), self.display_name);
+                inter.add_multiplier(KeyValues::Stats, 1.0 + (0.1 * level), self.name);
+                inter.add_multiplier(KeyValues::Skills, 1.0 + (0.1 * level), self.name);
             }
             StatTypes::Str => {
-                inter.add_multiplier(KeyValues::SoldierXp, 0.05 * level, self.display_name);
+                inter.add_multiplier(KeyValues::SoldierXp, 0.05 * level, self.name);
             }
             StatTypes::Cha => {
-                inter.add_multiplier(KeyValues::Coins, 1.0 + (0.05 * level), self.display_name);
+                inter.add_multiplier(KeyValues::Coins, 1.0 + (0.05 * level), self.name);
             }
             StatTypes::Faith => {
-                inter.add_multiplier(
-                    KeyValues::DivineFavor,
-                    1.0 + (0.05 * level),
-                    self.display_name,
-                );
+                inter.add_multiplier(KeyValues::DivineFavor, 1.0 + (0.05 * level), self.name);
+            }
+            StatTypes::Dex => {
+                for work in CRAFTING_WORKS {
+                    inter.add_multiplier(work.into(), 1.0 + (0.1 * level), self.name);
+                }
             }
-            _ => {}
         }
     }
 }
@@ -64,7 +65,7 @@ pub fn translate_stat(stat: StatTypes) -> Stat {
         StatTypes::Cha => Stat {
             name: stat,
             description: "Being liked has its benifits.",
-            effect_description: "Boosts Coin gain by 1 + level*0.05",
+            effect_description: "Boosts income from Merchant jobs by 1 + level*0.1\nBoosts Coin gain by 1 + level*0.05",
             display_name: "Charisma",
             required_tier: 1,
             icon: IconType::Cha.into(),
@@ -72,7 +73,7 @@ pub fn translate_stat(stat: StatTypes) -> Stat {
         StatTypes::Dex => Stat {
             name: stat,
             description: "Bendy!",
-            effect_description: "todo",
+            effect_description: "Boosts income from crafting jobs by 1 + level*0.1",
             display_name: "Dexterity",
             required_tier: 7,
             icon: IconType::Dex.into(),
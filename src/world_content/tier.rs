@@ -109,8 +109,8 @@ pub fn init_tiers() -> Vec<Tier> {
         Tier {
             level: 4,
             display_name: "Landowning peasant",
-            description: "todo",
-            unlocks: vec![ ],
+            description: "The land is yours, even if the lord still takes his share.",
+            unlocks: vec!["Light cavalry", "The priesthood"],
             starting_stats: get_stats_base(BaseStats {
                 str: 30.0,
                 int: 30.0,
@@ -126,8 +126,8 @@ pub fn init_tiers() -> Vec<Tier> {
         Tier {
             level: 5,
             display_name: "Farm Owner",
-            description: "todo",
-            unlocks: vec![ ],
+            description: "Others work your fields now.",
+            unlocks: vec!["Intellectual careers", "Elite cavalry"],
             starting_stats: get_stats_base(BaseStats {
                 str: 40.0,
                 int: 40.0,
@@ -143,8 +143,8 @@ pub fn init_tiers() -> Vec<Tier> {
         Tier {
             level: 6,
             display_name: "Estate Owner",
-            description: "todo",
-            unlocks: vec![ ],
+            description: "Your estate feeds a village.",
+            unlocks: vec!["Trade careers", "Command ranks", "Life events"],
             starting_stats: get_stats_base(BaseStats {
                 str: 50.0,
                 int: 50.0,
@@ -160,8 +160,8 @@ pub fn init_tiers() -> Vec<Tier> {
         Tier {
             level: 7,
             display_name: "Grand Estate Owner",
-            description: "todo",
-            unlocks: vec![ ],
+            description: "Your lands stretch further than you can ride in a day.",
+            unlocks: vec!["Merchants", "Generals", "Dexterity", "Farming from the start"],
             starting_stats: get_stats_base(BaseStats {
                 str: 70.0,
                 int: 70.0,
@@ -174,6 +174,124 @@ pub fn init_tiers() -> Vec<Tier> {
             starting_health: 0.4,
             purchasing_cost: 5_000_000.0,
         },
-
+        Tier {
+            level: 8,
+            display_name: "Low Noble",
+            description: "Your family has a name, and a crest to go with it.",
+            unlocks: vec!["Master crafts", "The Barrow", "Woodcutting from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 90.0,
+                int: 90.0,
+                cha: 90.0,
+                con: 90.0,
+                dex: 10.0,
+                faith: 90.0,
+            }),
+            starting_work: WorkTypes::Woodcutter,
+            starting_health: 0.5,
+            purchasing_cost: 5.0e7,
+        },
+        Tier {
+            level: 9,
+            display_name: "Middle Noble",
+            description: "Lesser nobles bow to you, greater ones barely notice you.",
+            unlocks: vec!["Weaponsmithing", "The Sepulchre", "Leather working from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 115.0,
+                int: 115.0,
+                cha: 115.0,
+                con: 115.0,
+                dex: 20.0,
+                faith: 115.0,
+            }),
+            starting_work: WorkTypes::LeatherWorker,
+            starting_health: 0.6,
+            purchasing_cost: 5.0e8,
+        },
+        Tier {
+            level: 10,
+            display_name: "High Noble",
+            description: "You sit at the king's table.",
+            unlocks: vec!["The Tumulus", "Noble Privilege", "Pottery from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 145.0,
+                int: 145.0,
+                cha: 145.0,
+                con: 145.0,
+                dex: 30.0,
+                faith: 145.0,
+            }),
+            starting_work: WorkTypes::Potter,
+            starting_health: 0.7,
+            purchasing_cost: 5.0e9,
+        },
+        Tier {
+            level: 11,
+            display_name: "Regent",
+            description: "You rule in the name of a king too young to do it himself.",
+            unlocks: vec!["The Tholos Tomb", "Bribe Charon 4", "Carpentry from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 180.0,
+                int: 180.0,
+                cha: 180.0,
+                con: 180.0,
+                dex: 45.0,
+                faith: 180.0,
+            }),
+            starting_work: WorkTypes::Carpenter,
+            starting_health: 0.8,
+            purchasing_cost: 5.0e10,
+        },
+        Tier {
+            level: 12,
+            display_name: "King",
+            description: "The crown is heavy, but it fits.",
+            unlocks: vec!["The Royal Tomb", "Soldier Experience 4", "A smithing apprenticeship from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 220.0,
+                int: 220.0,
+                cha: 220.0,
+                con: 220.0,
+                dex: 60.0,
+                faith: 220.0,
+            }),
+            starting_work: WorkTypes::BlacksmithApprentice,
+            starting_health: 0.9,
+            purchasing_cost: 5.0e11,
+        },
+        Tier {
+            level: 13,
+            display_name: "King of Kings",
+            description: "Kings send you tribute.",
+            unlocks: vec!["The Necropolis", "Intellectual Experience 2", "Smithing from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 270.0,
+                int: 270.0,
+                cha: 270.0,
+                con: 270.0,
+                dex: 80.0,
+                faith: 270.0,
+            }),
+            starting_work: WorkTypes::Blacksmith,
+            starting_health: 1.0,
+            purchasing_cost: 5.0e12,
+        },
+        Tier {
+            level: 14,
+            display_name: "The August One",
+            description: "They will build temples to you after you die.",
+            unlocks: vec!["The Pyramid", "Borrowed Time 4", "Goldsmithing from the start"],
+            starting_stats: get_stats_base(BaseStats {
+                str: 330.0,
+                int: 330.0,
+                cha: 330.0,
+                con: 330.0,
+                dex: 100.0,
+                faith: 330.0,
+            }),
+            starting_work: WorkTypes::Goldsmith,
+            starting_health: 1.1,
+            purchasing_cost: 5.0e13,
+        },
     ]
 }
@@ -18,7 +18,7 @@ pub struct Tomb {
 
 impl Gain for Tomb {
     fn gain(&self, intermediate: &mut IntermediateState) {
-        intermediate.set_base(KeyValues::Coins, self.coin_gain, self.display_name);
+        intermediate.set_base(KeyValues::Coins, self.coin_gain, self.name);
     }
 }
 
@@ -88,6 +88,62 @@ pub const fn translate_tomb(tomb: TombTypes) -> Tomb {
             required_tier: 7,
             coin_gain: 64.0e3,
         },
+        TombTypes::Barrow => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e12,
+            description: "A hill of earth raised over your bones",
+            display_name: "Barrow",
+            required_tier: 8,
+            coin_gain: 256.0e3,
+        },
+        TombTypes::Sepulchre => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e13,
+            description: "Carved into the rock, sealed with a stone",
+            display_name: "Sepulchre",
+            required_tier: 9,
+            coin_gain: 1.0e6,
+        },
+        TombTypes::Tumulus => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e14,
+            description: "The whole village carried dirt for a month",
+            display_name: "Tumulus",
+            required_tier: 10,
+            coin_gain: 4.0e6,
+        },
+        TombTypes::Tholos => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e15,
+            description: "A beehive of stone for a queen bee",
+            display_name: "Tholos Tomb",
+            required_tier: 11,
+            coin_gain: 16.0e6,
+        },
+        TombTypes::RoyalTomb => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e16,
+            description: "Your servants are buried with you. They did not volunteer",
+            display_name: "Royal Tomb",
+            required_tier: 12,
+            coin_gain: 64.0e6,
+        },
+        TombTypes::Necropolis => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e17,
+            description: "A whole city for the dead, and you are its king",
+            display_name: "Necropolis",
+            required_tier: 13,
+            coin_gain: 256.0e6,
+        },
+        TombTypes::Pyramid => Tomb {
+            name: tomb,
+            purchasing_cost: 1.0e18,
+            description: "Visible from every corner of your empire",
+            display_name: "Pyramid",
+            required_tier: 14,
+            coin_gain: 1.0e9,
+        },
     }
 }
 
@@ -2,6 +2,7 @@ use crate::engine::intermediate_state::{Gain, IntermediateState};
 use crate::engine::value_keys::KeyValues;
 use crate::game::Game;
 // use crate::input::stat::StatTypes;
+use crate::input::skill::SkillTypes;
 use crate::input::work::{WorkCategoryTypes, WorkTypes, WORK_SIZE};
 use serde::Serialize;
 use std::mem::{self, MaybeUninit};
@@ -16,31 +17,60 @@ pub struct Work {
     pub required_tier: u32,
     pub work_type: WorkCategoryTypes,
     pub xp_req_modifier: f64,
+    // Fraction of the money on hand earned as income every second
+    pub commission: f64,
 }
 
+// Works that pay more with higher Dexterity
+pub const CRAFTING_WORKS: [WorkTypes; 8] = [
+    WorkTypes::LeatherWorker,
+    WorkTypes::Potter,
+    WorkTypes::Carpenter,
+    WorkTypes::BlacksmithApprentice,
+    WorkTypes::Blacksmith,
+    WorkTypes::Goldsmith,
+    WorkTypes::GlassBlower,
+    WorkTypes::Weaponsmith,
+];
+
 impl Gain for Work {
     fn gain(&self, intermediate: &mut IntermediateState) {
         match self.name {
             WorkTypes::Mines => {
-                intermediate.add_base(KeyValues::Health, -5.0, self.display_name);
+                intermediate.add_base(KeyValues::Health, -5.0, self.name);
             }
             WorkTypes::Latrine => {
-                intermediate.add_base(KeyValues::Health, -4.0, self.display_name);
+                intermediate.add_base(KeyValues::Health, -4.0, self.name);
             }
             WorkTypes::GalleyRower => {
-                intermediate.add_base(KeyValues::Health, -3.0, self.display_name);
+                intermediate.add_base(KeyValues::Health, -3.0, self.name);
             }
             WorkTypes::Fields => {
-                intermediate.add_base(KeyValues::Health, -2.0, self.display_name);
+                intermediate.add_base(KeyValues::Health, -2.0, self.name);
             }
             WorkTypes::Mill => {
-                intermediate.add_base(KeyValues::Health, -1.0, self.display_name);
+                intermediate.add_base(KeyValues::Health, -1.0, self.name);
+            }
+            WorkTypes::ThessalianCavalry => {
+                intermediate.add_multiplier(KeyValues::Tactics, 1.2, self.name);
+            }
+            WorkTypes::CompanionCavalry => {
+                intermediate.add_multiplier(KeyValues::Tactics, 1.4, self.name);
+            }
+            WorkTypes::SquadCommander => {
+                intermediate.add_multiplier(KeyValues::Tactics, 1.6, self.name);
+            }
+            WorkTypes::Commander => {
+                intermediate.add_multiplier(KeyValues::Tactics, 1.8, self.name);
+            }
+            WorkTypes::General => {
+                intermediate.add_multiplier(KeyValues::Tactics, 2.0, self.name);
             }
             WorkTypes::Priest => {
-                intermediate.add_base(KeyValues::DivineFavor, 1.0, self.display_name);
+                intermediate.add_base(KeyValues::DivineFavor, 1.0, self.name);
             }
             WorkTypes::Bishop => {
-                intermediate.add_base(KeyValues::DivineFavor, 1.5, self.display_name);
+                intermediate.add_base(KeyValues::DivineFavor, 1.5, self.name);
             }
 
             _ => (),
@@ -58,6 +88,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 0,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 1.0,
+            commission: 0.0,
         },
         WorkTypes::Latrine => Work {
             name: work,
@@ -67,6 +98,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 0,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2.0,
+            commission: 0.0,
         },
         WorkTypes::GalleyRower => Work {
             name: work,
@@ -76,6 +108,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 0,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 4.0,
+            commission: 0.0,
         },
         WorkTypes::Fields => Work {
             name: work,
@@ -85,6 +118,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 0,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 8.0,
+            commission: 0.0,
         },
         WorkTypes::Mill => Work {
             name: work,
@@ -94,6 +128,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 0,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 16.0,
+            commission: 0.0,
         },
         WorkTypes::Weaver => Work {
             name: work,
@@ -103,6 +138,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 1,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 32.0,
+            commission: 0.0,
         },
         WorkTypes::Fisherman => Work {
             name: work,
@@ -112,6 +148,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 2,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 64.0,
+            commission: 0.0,
         },
         WorkTypes::Farmer => Work {
             name: work,
@@ -121,6 +158,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 3,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 128.0,
+            commission: 0.0,
         },
         WorkTypes::Woodcutter => Work {
             name: work,
@@ -130,6 +168,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 4,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2_000.0,
+            commission: 0.0,
         },
         WorkTypes::LeatherWorker => Work {
             name: work,
@@ -139,6 +178,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 5,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 512.0,
+            commission: 0.0,
         },
         WorkTypes::Potter => Work {
             name: work,
@@ -148,6 +188,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 6,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2.0e3,
+            commission: 0.0,
         },
         WorkTypes::Carpenter => Work {
             name: work,
@@ -157,6 +198,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 7,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 8.0e3,
+            commission: 0.0,
         },
         WorkTypes::BlacksmithApprentice => Work {
             name: work,
@@ -166,6 +208,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 7,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 32.0e3,
+            commission: 0.0,
         },
         WorkTypes::Blacksmith => Work {
             name: work,
@@ -175,6 +218,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 8,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2048.0,
+            commission: 0.0,
         },
         WorkTypes::Goldsmith => Work {
             name: work,
@@ -184,6 +228,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 8,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2048.0,
+            commission: 0.0,
         },
         WorkTypes::GlassBlower => Work {
             name: work,
@@ -193,6 +238,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 8,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2048.0,
+            commission: 0.0,
         },
         WorkTypes::Weaponsmith => Work {
             name: work,
@@ -202,6 +248,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 9,
             work_type: WorkCategoryTypes::Labor,
             xp_req_modifier: 2048.0,
+            commission: 0.0,
         },
         // ------------------Soldiers ---------------------
         WorkTypes::BaggageBoy => Work {
@@ -212,6 +259,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 2,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 64.0,
+            commission: 0.0,
         },
         WorkTypes::Slinger => Work {
             name: work,
@@ -221,6 +269,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 2,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 128.0,
+            commission: 0.0,
         },
         WorkTypes::Peltast => Work {
             name: work,
@@ -230,6 +279,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 2,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 256.0,
+            commission: 0.0,
         },
         WorkTypes::Pikeman => Work {
             name: work,
@@ -239,6 +289,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 2,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 512.0,
+            commission: 0.0,
         },
         WorkTypes::FootCompanion => Work {
             name: work,
@@ -248,6 +299,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 2,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 1000.0,
+            commission: 0.0,
         },
         WorkTypes::Hypaspist => Work {
             name: work,
@@ -257,6 +309,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 3,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 4_000.0,
+            commission: 0.0,
         },
         WorkTypes::LightCavalry => Work {
             name: work,
@@ -266,6 +319,118 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 4,
             work_type: WorkCategoryTypes::Soldier,
             xp_req_modifier: 32_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::ThessalianCavalry => Work {
+            name: work,
+            money: 2_000.0,
+            description: "The finest horsemen of Greece, riding in a rhombus behind their commander.",
+            display_name: "Thessalian Cavalry",
+            required_tier: 5,
+            work_type: WorkCategoryTypes::Soldier,
+            xp_req_modifier: 128_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::CompanionCavalry => Work {
+            name: work,
+            money: 5_000.0,
+            description: "You ride in the wedge at the king's side and break the enemy line.",
+            display_name: "Companion Cavalry",
+            required_tier: 5,
+            work_type: WorkCategoryTypes::Soldier,
+            xp_req_modifier: 512_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::SquadCommander => Work {
+            name: work,
+            money: 12_000.0,
+            description: "Men follow your orders now. Most of the time.",
+            display_name: "Squad Commander",
+            required_tier: 6,
+            work_type: WorkCategoryTypes::Soldier,
+            xp_req_modifier: 2_000_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::Commander => Work {
+            name: work,
+            money: 30_000.0,
+            description: "You lead a whole wing of the army into battle.",
+            display_name: "Commander",
+            required_tier: 6,
+            work_type: WorkCategoryTypes::Soldier,
+            xp_req_modifier: 8_000_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::General => Work {
+            name: work,
+            money: 80_000.0,
+            description: "Whole armies march where you point.",
+            display_name: "General",
+            required_tier: 7,
+            work_type: WorkCategoryTypes::Soldier,
+            xp_req_modifier: 32_000_000.0,
+            commission: 0.0,
+        },
+        // ------------------Intellectuals ---------------------
+        WorkTypes::Servant => Work {
+            name: work,
+            money: 40.0,
+            description: "Fetching, carrying and listening. You learn more than your masters think.",
+            display_name: "Servant",
+            required_tier: 5,
+            work_type: WorkCategoryTypes::Intellectual,
+            xp_req_modifier: 128.0,
+            commission: 0.0,
+        },
+        WorkTypes::Teacher => Work {
+            name: work,
+            money: 100.0,
+            description: "Teaching the children of the rich their letters.",
+            display_name: "Teacher",
+            required_tier: 5,
+            work_type: WorkCategoryTypes::Intellectual,
+            xp_req_modifier: 512.0,
+            commission: 0.0,
+        },
+        WorkTypes::Scribe => Work {
+            name: work,
+            money: 250.0,
+            description: "Contracts, letters and ledgers, all written by your hand.",
+            display_name: "Scribe",
+            required_tier: 5,
+            work_type: WorkCategoryTypes::Intellectual,
+            xp_req_modifier: 2_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::Librarian => Work {
+            name: work,
+            money: 600.0,
+            description: "Keeper of scrolls that few can read.",
+            display_name: "Librarian",
+            required_tier: 6,
+            work_type: WorkCategoryTypes::Intellectual,
+            xp_req_modifier: 8_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::Scholar => Work {
+            name: work,
+            money: 1_500.0,
+            description: "You argue about the nature of things for a living.",
+            display_name: "Scholar",
+            required_tier: 6,
+            work_type: WorkCategoryTypes::Intellectual,
+            xp_req_modifier: 32_000.0,
+            commission: 0.0,
+        },
+        WorkTypes::Philosopher => Work {
+            name: work,
+            money: 4_000.0,
+            description: "Kings ask for your advice, and sometimes even follow it.",
+            display_name: "Philosopher",
+            required_tier: 7,
+            work_type: WorkCategoryTypes::Intellectual,
+            xp_req_modifier: 128_000.0,
+            commission: 0.0,
         },
         WorkTypes::Priest => Work {
             name: work,
@@ -275,6 +440,7 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 4,
             work_type: WorkCategoryTypes::Priest,
             xp_req_modifier: 1_000.0,
+            commission: 0.0,
         },
         WorkTypes::Bishop => Work {
             name: work,
@@ -284,17 +450,30 @@ const fn translate_work(work: WorkTypes) -> Work {
             required_tier: 4,
             work_type: WorkCategoryTypes::Priest,
             xp_req_modifier: 4_000.0,
+            commission: 0.0,
         },
         // Int based work
-        // WorkTypes::Trader => Work {
-        //     name: work,
-        //     money: 150.0,
-        //     description: "Sell low, Buy when high",
-        //     display_name: "Trader",
-        //     required_tier: 7,
-        //     work_type: StatTypes::Intellectual,
-        //     xp_req_modifier: 4_000.0,
-        // },
+        // ------------------Merchants ---------------------
+        WorkTypes::Trader => Work {
+            name: work,
+            money: 20.0,
+            description: "Sell low, Buy when high",
+            display_name: "Trader",
+            required_tier: 6,
+            work_type: WorkCategoryTypes::Merchant,
+            xp_req_modifier: 4_000.0,
+            commission: 0.000_1,
+        },
+        WorkTypes::Merchant => Work {
+            name: work,
+            money: 50.0,
+            description: "Your ships carry the goods, your coin carries the risk.",
+            display_name: "Merchant",
+            required_tier: 7,
+            work_type: WorkCategoryTypes::Merchant,
+            xp_req_modifier: 64_000.0,
+            commission: 0.000_2,
+        },
     }
 }
 
@@ -329,17 +508,44 @@ pub fn should_unlock_work(work_type: WorkTypes, game: &Game) -> bool {
             game.state.works[work_type as usize - 1].level >= 10
                 || game.state.rebirth_stats.tier >= 6
         }
-        WorkTypes::Farmer => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::Woodcutter => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::LeatherWorker => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::Potter => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::Carpenter => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::BlacksmithApprentice => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::Blacksmith => game.state.works[work_type as usize - 1].level >= 10,
-        WorkTypes::Goldsmith => game.state.works[work_type as usize - 1].level >= 10,
+        WorkTypes::Farmer => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 7
+        }
+        WorkTypes::Woodcutter => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 8
+        }
+        WorkTypes::LeatherWorker => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 9
+        }
+        WorkTypes::Potter => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 10
+        }
+        WorkTypes::Carpenter => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 11
+        }
+        WorkTypes::BlacksmithApprentice => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 12
+        }
+        WorkTypes::Blacksmith => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 13
+        }
+        WorkTypes::Goldsmith => {
+            game.state.works[work_type as usize - 1].level >= 10
+                || game.state.rebirth_stats.tier >= 14
+        }
         WorkTypes::GlassBlower => game.state.works[work_type as usize - 1].level >= 10,
         WorkTypes::Weaponsmith => game.state.works[work_type as usize - 1].level >= 10,
-        // WorkTypes::Trader => game.state.works[work_type as usize - 1].level >= 10,
+
+        // Merchant
+        WorkTypes::Trader => true,
+        WorkTypes::Merchant => game.state.works[work_type as usize - 1].level >= 10,
 
         // Soldier
         WorkTypes::BaggageBoy => true,
@@ -349,6 +555,30 @@ pub fn should_unlock_work(work_type: WorkTypes, game: &Game) -> bool {
         WorkTypes::FootCompanion => game.state.works[work_type as usize - 1].level >= 10,
         WorkTypes::Hypaspist => game.state.works[work_type as usize - 1].level >= 10,
         WorkTypes::LightCavalry => game.state.works[work_type as usize - 1].level >= 10,
+        WorkTypes::ThessalianCavalry => {
+            game.state.works[WorkTypes::LightCavalry as usize].level >= 10
+                && tactics_level(game) >= 10.0
+        }
+        WorkTypes::CompanionCavalry => {
+            game.state.works[work_type as usize - 1].level >= 10 && tactics_level(game) >= 20.0
+        }
+        WorkTypes::SquadCommander => {
+            game.state.works[work_type as usize - 1].level >= 10 && tactics_level(game) >= 30.0
+        }
+        WorkTypes::Commander => {
+            game.state.works[work_type as usize - 1].level >= 10 && tactics_level(game) >= 40.0
+        }
+        WorkTypes::General => {
+            game.state.works[work_type as usize - 1].level >= 10 && tactics_level(game) >= 50.0
+        }
+
+        // Intellectual
+        WorkTypes::Servant => true,
+        WorkTypes::Teacher => game.state.works[work_type as usize - 1].level >= 10,
+        WorkTypes::Scribe => game.state.works[work_type as usize - 1].level >= 10,
+        WorkTypes::Librarian => game.state.works[work_type as usize - 1].level >= 10,
+        WorkTypes::Scholar => game.state.works[work_type as usize - 1].level >= 10,
+        WorkTypes::Philosopher => game.state.works[work_type as usize - 1].level >= 10,
 
         //Priest/Faith
         WorkTypes::Priest => true,
@@ -356,6 +586,11 @@ pub fn should_unlock_work(work_type: WorkTypes, game: &Game) -> bool {
     }
 }
 
+// The upper military ranks also need to know how to lead
+fn tactics_level(game: &Game) -> f64 {
+    game.state.skills[SkillTypes::Tactics as usize].level
+}
+
 pub fn should_be_visible_work(input_work: WorkTypes, game: &Game) -> bool {
     let work = game.world.get_work(input_work);
     work.required_tier <= game.state.rebirth_stats.tier
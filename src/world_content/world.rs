@@ -3,10 +3,15 @@ use serbia::serbia;
 use serde::Serialize;
 use std::sync::Mutex;
 
+use super::achievement::{get_achievements, Achievement};
 use super::activity::{get_activities, Activity};
 use super::blessing::{get_blessings, Blessing};
 use super::boost_item::{get_boost_items, BoostItem};
+use super::challenge::{get_challenges, Challenge};
+use super::deity::{get_deities, Deity};
 use super::housing::{get_housings, Housing};
+use super::karma_upgrade::{get_karma_upgrades, KarmaUpgrade};
+use super::life_event::{get_life_events, LifeEvent};
 use super::rebirth_upgrade::{get_rebirth_upgrades, RebirthUpgrade};
 use super::settings::Settings;
 use super::skill::{get_skills, Skill};
@@ -15,10 +20,15 @@ use super::tier::{init_tiers, Tier};
 use super::tomb::{get_tombs, Tomb};
 use super::work::{get_works, Work};
 use crate::icon::{get_icons, Icon};
+use crate::input::achievement::ACHIEVEMENT_SIZE;
 use crate::input::activity::{ActivityTypes, ACTIVITY_SIZE};
 use crate::input::blessing::BLESSING_SIZE;
 use crate::input::boost_item::BOOST_ITEM_SIZE;
+use crate::input::challenge::CHALLENGE_SIZE;
+use crate::input::deity::DEITY_SIZE;
 use crate::input::housing::{HousingTypes, HOUSING_SIZE};
+use crate::input::karma_upgrade::KARMA_UPGRADE_SIZE;
+use crate::input::life_event::LIFE_EVENT_SIZE;
 use crate::input::rebirth_upgrade::{RebirthUpgradeTypes, REBIRTH_UPGRADE_SIZE};
 use crate::input::skill::SKILL_SIZE;
 use crate::input::stat::STAT_SIZE;
@@ -30,11 +40,16 @@ use std::collections::BTreeMap;
 #[serbia]
 #[derive(Serialize)]
 pub struct World {
+    pub achievements: [Achievement; ACHIEVEMENT_SIZE],
     activities: [Activity; ACTIVITY_SIZE],
     pub blessings: [Blessing; BLESSING_SIZE],
     // #[serde(serialize_with = "<[_]>::serialize")]
     pub boost_items: [BoostItem; BOOST_ITEM_SIZE],
+    pub challenges: [Challenge; CHALLENGE_SIZE],
+    pub deities: [Deity; DEITY_SIZE],
     housing: [Housing; HOUSING_SIZE],
+    pub karma_upgrades: [KarmaUpgrade; KARMA_UPGRADE_SIZE],
+    pub life_events: [LifeEvent; LIFE_EVENT_SIZE],
     pub rebirth_upgrades: [RebirthUpgrade; REBIRTH_UPGRADE_SIZE],
     pub settings: Settings,
     pub skills: [Skill; SKILL_SIZE],
@@ -65,13 +80,18 @@ impl World {
 impl Default for World {
     fn default() -> World {
         World {
+            achievements: get_achievements(),
             tiers: init_tiers(),
             works: get_works(),
             housing: get_housings(),
             boost_items: get_boost_items(),
+            challenges: get_challenges(),
+            deities: get_deities(),
             activities: get_activities(),
             tombs: get_tombs(),
             rebirth_upgrades: get_rebirth_upgrades(),
+            karma_upgrades: get_karma_upgrades(),
+            life_events: get_life_events(),
             stats: get_stats(),
             settings: Settings::default(),
             blessings: get_blessings(),
@@ -0,0 +1,137 @@
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::engine::{character_death_update, engine_run};
+use one_life::events::GameEventType;
+use one_life::game::{Game, GameSave};
+use one_life::input::achievement::AchievementTypes;
+use one_life::input::work::WorkTypes;
+use one_life::util::{run_until_dead, set_full_auto};
+use one_life::{do_rebirth_internal, get_achievements_internal, get_value_breakdown_internal};
+
+mod common;
+use common::load;
+
+fn is_unlocked(game: &Game, achievement: AchievementTypes) -> bool {
+    game.state.rebirth_stats.achievements[achievement as usize].is_unlocked
+}
+
+#[test]
+fn test_job_level_achievement() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    let labor_xp = game.intermediate_state.get_multiplier(KeyValues::LaborXp);
+    game.state.works[WorkTypes::Mines as usize].level = 24;
+    engine_run(&mut game);
+    assert!(!is_unlocked(&game, AchievementTypes::Journeyman));
+
+    game.state.works[WorkTypes::Mines as usize].level = 25;
+    engine_run(&mut game);
+    assert!(is_unlocked(&game, AchievementTypes::Journeyman));
+    assert!(!is_unlocked(&game, AchievementTypes::Master));
+    assert!(game.events.drain().iter().any(|e| e.event
+        == GameEventType::AchievementUnlocked {
+            achievement: AchievementTypes::Journeyman
+        }));
+
+    // The bonus is there from the next step on
+    engine_run(&mut game);
+    let multiplier = game.intermediate_state.get_multiplier(KeyValues::LaborXp);
+    assert!((multiplier - labor_xp * 1.1).abs() < 1e-9);
+    let breakdown = get_value_breakdown_internal(KeyValues::LaborXp, &game);
+    assert!(breakdown
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Achievement(AchievementTypes::Journeyman)));
+}
+
+#[test]
+fn test_achievements_persist() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    for tomb in game.state.tombs.iter_mut() {
+        tomb.is_purchased = true;
+    }
+    engine_run(&mut game);
+    assert!(is_unlocked(&game, AchievementTypes::Undertaker));
+    run_until_dead(&mut game);
+
+    do_rebirth_internal(&mut game);
+    assert!(game.state.tombs.iter().all(|t| !t.is_purchased));
+    assert!(is_unlocked(&game, AchievementTypes::Undertaker));
+    assert!(get_value_breakdown_internal(KeyValues::Coins, &game)
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Achievement(AchievementTypes::Undertaker)));
+
+    let save = serde_json::to_string(&GameSave::from(&game)).unwrap();
+    let game = load(serde_json::from_str(&save).unwrap());
+    assert!(is_unlocked(&game, AchievementTypes::Undertaker));
+}
+
+#[test]
+fn test_rapid_ascent() {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = 3;
+    save.state.rebirth_stats.rebirth_count = 11;
+    let mut game = load(save);
+    engine_run(&mut game);
+    assert!(!is_unlocked(&game, AchievementTypes::RapidAscent));
+
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = 3;
+    save.state.rebirth_stats.rebirth_count = 10;
+    let mut game = load(save);
+    engine_run(&mut game);
+    assert!(is_unlocked(&game, AchievementTypes::RapidAscent));
+}
+
+#[test]
+fn test_died_young() {
+    let mut save = GameSave::default();
+    set_full_auto(&mut save.meta_data.options);
+    let mut game = load(save);
+    run_until_dead(&mut game);
+    assert!(game.state.life_stats.age > 30.0 * 365.0);
+    assert!(!is_unlocked(&game, AchievementTypes::DiedYoung));
+
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    // Not before the life is over
+    game.state.life_stats.age = 20.0 * 365.0;
+    engine_run(&mut game);
+    assert!(!is_unlocked(&game, AchievementTypes::DiedYoung));
+    character_death_update(&mut game);
+    assert!(is_unlocked(&game, AchievementTypes::DiedYoung));
+}
+
+#[test]
+fn test_achievement_progress() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    game.state.works[WorkTypes::Mines as usize].level = 50;
+    engine_run(&mut game);
+    let progress = get_achievements_internal(&game);
+    let journeyman = &progress[AchievementTypes::Journeyman as usize];
+    assert!(journeyman.is_unlocked);
+    assert_eq!(journeyman.progress, journeyman.target);
+    let master = &progress[AchievementTypes::Master as usize];
+    assert!(!master.is_unlocked);
+    assert_eq!(master.progress, 50.0);
+    assert_eq!(master.target, 100.0);
+}
+
+#[test]
+fn test_save_without_achievements_loads() {
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    save["state"]["rebirth_stats"]
+        .as_object_mut()
+        .unwrap()
+        .remove("achievements");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert!(save
+        .state
+        .rebirth_stats
+        .achievements
+        .iter()
+        .all(|a| !a.is_unlocked));
+}
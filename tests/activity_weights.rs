@@ -0,0 +1,173 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::{Game, GameSave};
+use one_life::input::activity::ActivityTypes;
+use one_life::presets::rebirth_3;
+use one_life::util::run_until_dead;
+use one_life::{
+    do_rebirth_internal, get_value_breakdown_internal, set_activity_internal,
+    set_activity_weight_internal,
+};
+
+mod common;
+use common::load;
+
+fn base_gain(game: &Game, activity: ActivityTypes) -> f64 {
+    game.world.get_activity(activity).base_gain_amount
+}
+
+#[test]
+fn test_single_activity_gets_all_the_time() {
+    let mut game = load(GameSave::default());
+    set_activity_internal(ActivityTypes::Studying, &mut game);
+    engine_run(&mut game);
+    let breakdown = get_value_breakdown_internal(KeyValues::Int, &game);
+    assert_eq!(breakdown.bases.len(), 1);
+    assert_eq!(
+        breakdown.bases[0].source,
+        GainSource::Activity(ActivityTypes::Studying)
+    );
+    assert_eq!(
+        breakdown.bases[0].amount,
+        base_gain(&game, ActivityTypes::Studying)
+    );
+    assert!(get_value_breakdown_internal(KeyValues::Con, &game)
+        .bases
+        .is_empty());
+}
+
+#[test]
+fn test_weights_split_the_gains() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    set_activity_weight_internal(ActivityTypes::Run, 75, &mut game);
+    set_activity_weight_internal(ActivityTypes::Studying, 25, &mut game);
+    engine_run(&mut game);
+    let con = get_value_breakdown_internal(KeyValues::Con, &game);
+    let int = get_value_breakdown_internal(KeyValues::Int, &game);
+    assert_eq!(
+        con.bases[0].amount,
+        base_gain(&game, ActivityTypes::Run) * 0.75
+    );
+    assert_eq!(
+        int.bases[0].amount,
+        base_gain(&game, ActivityTypes::Studying) * 0.25
+    );
+
+    // Weights are relative to each other
+    set_activity_weight_internal(ActivityTypes::Run, 25, &mut game);
+    engine_run(&mut game);
+    let con = get_value_breakdown_internal(KeyValues::Con, &game);
+    assert_eq!(
+        con.bases[0].amount,
+        base_gain(&game, ActivityTypes::Run) * 0.5
+    );
+
+    // Picking a single activity drops the split
+    set_activity_internal(ActivityTypes::Run, &mut game);
+    engine_run(&mut game);
+    assert_eq!(game.input.activity_weights.iter().sum::<u32>(), 0);
+    let con = get_value_breakdown_internal(KeyValues::Con, &game);
+    assert_eq!(con.bases[0].amount, base_gain(&game, ActivityTypes::Run));
+    assert!(get_value_breakdown_internal(KeyValues::Int, &game)
+        .bases
+        .is_empty());
+}
+
+#[test]
+fn test_weights_are_capped() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    set_activity_weight_internal(ActivityTypes::Run, 1000, &mut game);
+    assert_eq!(
+        game.input.activity_weights[ActivityTypes::Run as usize],
+        100
+    );
+}
+
+#[test]
+fn test_locked_activities_get_no_weight() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    assert!(!game.state.activities[ActivityTypes::Flirt as usize].is_unlocked);
+    set_activity_weight_internal(ActivityTypes::Run, 50, &mut game);
+    set_activity_weight_internal(ActivityTypes::Flirt, 50, &mut game);
+    assert_eq!(
+        game.input.activity_weights[ActivityTypes::Flirt as usize],
+        0
+    );
+    assert_eq!(
+        game.input.activity_shares(),
+        vec![(ActivityTypes::Run, 1.0)]
+    );
+}
+
+#[test]
+fn test_weights_are_replayed() {
+    let mut game = load(GameSave::default());
+    for _ in 0..10 {
+        engine_run(&mut game);
+    }
+    set_activity_weight_internal(ActivityTypes::Run, 40, &mut game);
+    set_activity_weight_internal(ActivityTypes::Studying, 60, &mut game);
+    let weights = game.input.activity_weights;
+    run_until_dead(&mut game);
+
+    do_rebirth_internal(&mut game);
+    assert_eq!(game.input.activity_weights.iter().sum::<u32>(), 0);
+    game.state.life_stats.replaying = true;
+    for _ in 0..10 {
+        engine_run(&mut game);
+    }
+    assert_eq!(game.input.activity_weights, weights);
+}
+
+#[test]
+fn test_save_without_weights_loads() {
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    save["input"]["activity"] = serde_json::json!("Studying");
+    save["input"]
+        .as_object_mut()
+        .unwrap()
+        .remove("activity_weights");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert!(save.input.activity_weights.iter().all(|w| *w == 0));
+    assert_eq!(
+        save.input.activity_shares(),
+        vec![(ActivityTypes::Studying, 1.0)]
+    );
+}
+
+// Saved by the game before activities could be split
+#[test]
+fn test_baseline_save_has_a_single_activity() {
+    let save: GameSave = serde_json::from_str(include_str!("fixtures/baseline_save.json")).unwrap();
+    assert!(save.input.activity_weights.iter().all(|w| *w == 0));
+    assert_eq!(
+        save.input.activity_shares(),
+        vec![(ActivityTypes::Run, 1.0)]
+    );
+}
+
+#[test]
+fn test_preset_splits_activities() {
+    let mut game = load(rebirth_3());
+    while game.state.life_stats.current_tick <= 4000 {
+        engine_run(&mut game);
+    }
+    assert_eq!(
+        game.input.activity_shares(),
+        vec![
+            (ActivityTypes::Run, 2.0 / 3.0),
+            (ActivityTypes::Studying, 1.0 / 3.0)
+        ]
+    );
+    while game.state.life_stats.current_tick <= 30000 {
+        engine_run(&mut game);
+    }
+    assert_eq!(
+        game.input.activity_shares(),
+        vec![(ActivityTypes::Flirt, 1.0)]
+    );
+}
@@ -0,0 +1,160 @@
+use one_life::engine::auto_functions::{auto_buy_item, auto_living};
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::engine::{character_death_update, engine_run};
+use one_life::events::GameEventType;
+use one_life::game::{Game, GameSave};
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::challenge::ChallengeTypes;
+use one_life::input::housing::HousingTypes;
+use one_life::input::work::WorkTypes;
+use one_life::util::run_until_dead;
+use one_life::{
+    can_buy_item, do_rebirth_internal, do_rebirth_replay_internal, get_value_breakdown_internal,
+    set_housing_internal, set_work_internal, start_challenge_internal,
+};
+
+mod common;
+use common::{load, load_tier};
+
+// A dead character at the given tier, ready to start a challenge
+fn dead_game(tier: u32) -> Game {
+    let mut game = load_tier(tier);
+    engine_run(&mut game);
+    character_death_update(&mut game);
+    game
+}
+
+#[test]
+fn test_challenge_requires_tier() {
+    let mut game = dead_game(1);
+    start_challenge_internal(ChallengeTypes::LaborOnly, &mut game);
+    assert!(game.state.life_stats.dead);
+    assert_eq!(game.state.life_stats.challenge, None);
+
+    let mut game = dead_game(2);
+    start_challenge_internal(ChallengeTypes::NoBoostItems, &mut game);
+    assert!(game.state.life_stats.dead);
+    start_challenge_internal(ChallengeTypes::LaborOnly, &mut game);
+    assert!(!game.state.life_stats.dead);
+    assert_eq!(
+        game.state.life_stats.challenge,
+        Some(ChallengeTypes::LaborOnly)
+    );
+}
+
+#[test]
+fn test_challenge_rules() {
+    let mut game = dead_game(3);
+    start_challenge_internal(ChallengeTypes::LaborOnly, &mut game);
+    set_work_internal(WorkTypes::BaggageBoy, &mut game);
+    assert_ne!(game.input.work, WorkTypes::BaggageBoy);
+    set_work_internal(WorkTypes::Latrine, &mut game);
+    assert_eq!(game.input.work, WorkTypes::Latrine);
+
+    let mut game = dead_game(3);
+    start_challenge_internal(ChallengeTypes::StoneFloor, &mut game);
+    set_housing_internal(HousingTypes::ComfortableSpot, &mut game);
+    assert_eq!(game.input.housing, HousingTypes::StoneFloor);
+    game.state.items.money = 1e9;
+    for housing in game.state.housing.iter_mut() {
+        housing.is_unlocked = true;
+    }
+    auto_living(&mut game);
+    assert_eq!(game.input.housing, HousingTypes::StoneFloor);
+
+    let mut game = dead_game(3);
+    start_challenge_internal(ChallengeTypes::NoBoostItems, &mut game);
+    game.state.items.money = 1e9;
+    for item in game.state.boost_items.iter_mut() {
+        item.is_unlocked = true;
+        item.is_visible = true;
+    }
+    assert!(!can_buy_item(BoostItemTypes::Book, &mut game));
+    auto_buy_item(&mut game);
+    assert!(game.state.boost_items.iter().all(|i| !i.is_purchased));
+
+    // A normal rebirth is back to the usual rules
+    run_until_dead(&mut game);
+    do_rebirth_internal(&mut game);
+    assert_eq!(game.state.life_stats.challenge, None);
+    game.state.items.money = 1e9;
+    assert!(can_buy_item(BoostItemTypes::Book, &mut game));
+}
+
+#[test]
+fn test_challenge_completion() {
+    let mut game = dead_game(2);
+    start_challenge_internal(ChallengeTypes::StoneFloor, &mut game);
+    // Coins come from the tombs
+    for tomb in game.state.tombs.iter_mut().take(5) {
+        tomb.is_purchased = true;
+    }
+    engine_run(&mut game);
+    character_death_update(&mut game);
+    let challenge = &game.state.rebirth_stats.challenges[ChallengeTypes::StoneFloor as usize];
+    assert!(challenge.is_completed);
+    assert!(challenge.best_coins_gain >= 50.0);
+    assert!(game.events.drain().iter().any(|e| e.event
+        == GameEventType::ChallengeCompleted {
+            challenge: ChallengeTypes::StoneFloor
+        }));
+
+    // The reward stays with every life after it
+    do_rebirth_internal(&mut game);
+    assert!(get_value_breakdown_internal(KeyValues::Health, &game)
+        .bases
+        .iter()
+        .any(|b| b.source == GainSource::Challenge(ChallengeTypes::StoneFloor) && b.amount == 2.0));
+}
+
+#[test]
+fn test_challenge_not_completed_below_goal() {
+    let mut game = dead_game(2);
+    start_challenge_internal(ChallengeTypes::LaborOnly, &mut game);
+    engine_run(&mut game);
+    character_death_update(&mut game);
+    let challenge = &game.state.rebirth_stats.challenges[ChallengeTypes::LaborOnly as usize];
+    assert!(!challenge.is_completed);
+    assert!(challenge.best_coins_gain < 50.0);
+}
+
+#[test]
+fn test_replay_keeps_the_challenge() {
+    let mut game = dead_game(2);
+    start_challenge_internal(ChallengeTypes::StoneFloor, &mut game);
+    run_until_dead(&mut game);
+    do_rebirth_replay_internal(&mut game);
+    assert_eq!(
+        game.state.life_stats.challenge,
+        Some(ChallengeTypes::StoneFloor)
+    );
+
+    let save = serde_json::to_string(&GameSave::from(&game)).unwrap();
+    let game = load(serde_json::from_str(&save).unwrap());
+    assert_eq!(
+        game.state.life_stats.challenge,
+        Some(ChallengeTypes::StoneFloor)
+    );
+}
+
+#[test]
+fn test_save_without_challenges_loads() {
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    save["state"]["rebirth_stats"]
+        .as_object_mut()
+        .unwrap()
+        .remove("challenges");
+    save["state"]["life_stats"]
+        .as_object_mut()
+        .unwrap()
+        .remove("challenge");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert_eq!(save.state.life_stats.challenge, None);
+    assert!(save
+        .state
+        .rebirth_stats
+        .challenges
+        .iter()
+        .all(|c| !c.is_completed));
+}
@@ -0,0 +1,98 @@
+use one_life::clock::{Clock, ManualClock};
+use one_life::game::Game;
+use one_life::{paused_internal, tick_internal, TICK_MS};
+
+fn make_game(clock: &ManualClock) -> Game {
+    let mut game = Game::with_clock(clock.clone());
+    game.meta_data.game_speed = 1;
+    game.meta_data.autosave = false;
+    game
+}
+
+#[test]
+fn test_on_time_tick_has_no_missed_time() {
+    let clock = ManualClock::new(1000.0);
+    let mut game = make_game(&clock);
+    for _ in 0..10 {
+        clock.advance(TICK_MS);
+        tick_internal(&mut game);
+    }
+    assert_eq!(game.state.life_stats.current_tick, 10);
+    assert!(game.meta_data.missed_time.abs() < 1e-6);
+    assert_eq!(game.meta_data.saved_ticks, 0.0);
+}
+
+#[test]
+fn test_missed_time_is_caught_up() {
+    let clock = ManualClock::new(0.0);
+    let mut game = make_game(&clock);
+    clock.advance(TICK_MS * 4.0 + 1.0);
+    tick_internal(&mut game);
+    // One regular tick plus three missed ones
+    assert_eq!(game.state.life_stats.current_tick, 4);
+    assert!((game.meta_data.missed_time - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_missed_time_beyond_cap_is_caught_up_in_coarse_steps() {
+    let clock = ManualClock::new(0.0);
+    let mut game = make_game(&clock);
+    game.meta_data.options.max_missed_ticks = 5;
+    clock.advance(TICK_MS * 21.0 + 1.0);
+    tick_internal(&mut game);
+    // One regular tick, catch-up ticks 0..=5, then the rest in coarse steps
+    assert_eq!(game.state.life_stats.current_tick, 21);
+    assert_eq!(game.meta_data.saved_ticks, 0.0);
+    assert!((game.meta_data.missed_time - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_missed_time_without_catch_up_becomes_saved_ticks() {
+    let clock = ManualClock::new(0.0);
+    let mut game = make_game(&clock);
+    game.meta_data.options.use_missed_ticks = false;
+    clock.advance(TICK_MS * 11.0 + 1.0);
+    tick_internal(&mut game);
+    assert_eq!(game.state.life_stats.current_tick, 1);
+    assert_eq!(game.meta_data.saved_ticks, 10.0);
+}
+
+#[test]
+fn test_early_tick_is_skipped() {
+    let clock = ManualClock::new(0.0);
+    let mut game = make_game(&clock);
+    clock.advance(TICK_MS);
+    tick_internal(&mut game);
+    // Called again without time passing, the first one runs ahead of schedule
+    // and the second is skipped until the clock catches up
+    tick_internal(&mut game);
+    tick_internal(&mut game);
+    assert_eq!(game.state.life_stats.current_tick, 2);
+    clock.advance(TICK_MS * 2.0);
+    tick_internal(&mut game);
+    assert_eq!(game.state.life_stats.current_tick, 3);
+    assert!(game.meta_data.missed_time.abs() < 1e-6);
+}
+
+#[test]
+fn test_paused_time_becomes_saved_ticks() {
+    let clock = ManualClock::new(0.0);
+    let mut game = make_game(&clock);
+    for _ in 0..30 {
+        clock.advance(TICK_MS);
+        paused_internal(&mut game);
+    }
+    assert_eq!(game.state.life_stats.current_tick, 0);
+    assert!((game.meta_data.saved_ticks - 30.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_should_autosave_after_a_minute() {
+    let clock = ManualClock::new(0.0);
+    let mut game = make_game(&clock);
+    game.meta_data.autosave = true;
+    clock.advance(59_999.0);
+    assert!(!game.meta_data.should_autosave(clock.now()));
+    clock.advance(1.0);
+    assert!(game.meta_data.should_autosave(clock.now()));
+}
@@ -0,0 +1,18 @@
+// Each test binary only uses some of these
+#![allow(dead_code)]
+
+use one_life::clock::ManualClock;
+use one_life::game::{Game, GameSave};
+
+// Time only moves when a test advances the clock
+pub fn load(save: GameSave) -> Game {
+    let mut game = Game::with_clock(ManualClock::new(0.0));
+    game.load_game(save);
+    game
+}
+
+pub fn load_tier(tier: u32) -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = tier;
+    load(save)
+}
@@ -0,0 +1,193 @@
+use one_life::engine::auto_functions::auto_buy_blessing;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::engine::{character_death_update, engine_run};
+use one_life::events::GameEventType;
+use one_life::game::{Game, GameSave};
+use one_life::input::blessing::BlessingTypes;
+use one_life::input::deity::DeityTypes;
+use one_life::{
+    buy_blessing_internal, choose_deity_internal, do_rebirth_internal, do_rebirth_replay_internal,
+    get_value_breakdown_internal,
+};
+
+mod common;
+use common::load;
+
+fn faithful_game() -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.unlocks.has_faith = true;
+    let mut game = load(save);
+    engine_run(&mut game);
+    game
+}
+
+fn level(game: &Game, blessing: BlessingTypes) -> u32 {
+    game.state.blessings[blessing as usize].level
+}
+
+#[test]
+fn test_no_patron_no_blessings() {
+    let mut game = faithful_game();
+    game.state.items.divine_favor = 1e6;
+    engine_run(&mut game);
+    assert!(game.state.blessings.iter().all(|b| !b.is_visible));
+    buy_blessing_internal(BlessingTypes::AthenasWisdom, &mut game);
+    auto_buy_blessing(&mut game);
+    assert!(game.state.blessings.iter().all(|b| b.level == 0));
+    assert_eq!(game.state.items.divine_favor, 1e6);
+}
+
+// A blessed life next to one with another patron, or none at all
+fn blessed_game(deity: Option<DeityTypes>) -> Game {
+    let mut game = faithful_game();
+    if let Some(deity) = deity {
+        choose_deity_internal(deity, &mut game);
+    }
+    game.state.items.divine_favor = 1e6;
+    engine_run(&mut game);
+    auto_buy_blessing(&mut game);
+    engine_run(&mut game);
+    game
+}
+
+#[test]
+fn test_patron_choice_trades_off() {
+    let athena = blessed_game(Some(DeityTypes::Athena));
+    let herucles = blessed_game(Some(DeityTypes::Herucles));
+    let none = blessed_game(None);
+    let multiplier = |game: &Game, key| game.intermediate_state.get_multiplier(key);
+
+    // Each patron is better at their own stat and worse at the other
+    assert!(multiplier(&athena, KeyValues::Int) > multiplier(&herucles, KeyValues::Int));
+    assert!(multiplier(&athena, KeyValues::Str) < multiplier(&herucles, KeyValues::Str));
+    // Going without a patron avoids the drawback but gets no blessing
+    assert!(multiplier(&none, KeyValues::Str) > multiplier(&athena, KeyValues::Str));
+    assert!(multiplier(&none, KeyValues::Int) < multiplier(&athena, KeyValues::Int));
+}
+
+#[test]
+fn test_patron_limits_blessings() {
+    let mut game = faithful_game();
+    choose_deity_internal(DeityTypes::Athena, &mut game);
+    assert_eq!(game.input.deity, Some(DeityTypes::Athena));
+    assert!(game.events.drain().iter().any(|e| e.event
+        == GameEventType::DeityChosen {
+            deity: DeityTypes::Athena
+        }));
+    // Only one patron per life
+    choose_deity_internal(DeityTypes::Poseidon, &mut game);
+    assert_eq!(game.input.deity, Some(DeityTypes::Athena));
+
+    game.state.items.divine_favor = 1e6;
+    engine_run(&mut game);
+    assert!(!game.state.blessings[BlessingTypes::PoseidonsSturdiness as usize].is_visible);
+    buy_blessing_internal(BlessingTypes::PoseidonsSturdiness, &mut game);
+    assert_eq!(level(&game, BlessingTypes::PoseidonsSturdiness), 0);
+
+    auto_buy_blessing(&mut game);
+    assert!(level(&game, BlessingTypes::AthenasWisdom) > 0);
+    for blessing in [
+        BlessingTypes::HeruclesStrength,
+        BlessingTypes::AfroditesCharm,
+        BlessingTypes::PoseidonsSturdiness,
+        BlessingTypes::HermesSwiftness,
+        BlessingTypes::HadesBargain,
+    ] {
+        assert_eq!(level(&game, blessing), 0);
+    }
+}
+
+#[test]
+fn test_patron_cost_curve_and_drawback() {
+    let mut game = faithful_game();
+    let str_xp = game.intermediate_state.get_multiplier(KeyValues::Str);
+    choose_deity_internal(DeityTypes::Athena, &mut game);
+    game.state.items.divine_favor = 1e6;
+    engine_run(&mut game);
+    buy_blessing_internal(BlessingTypes::AthenasWisdom, &mut game);
+    buy_blessing_internal(BlessingTypes::AthenasWisdom, &mut game);
+    engine_run(&mut game);
+    let cost = game.state.blessings[BlessingTypes::AthenasWisdom as usize].next_level_cost;
+    assert!((cost - 100.0 * 1.3f64.powi(2)).abs() < 1e-9);
+
+    let multiplier = game.intermediate_state.get_multiplier(KeyValues::Str);
+    assert!((multiplier - str_xp * 0.8).abs() < 1e-9);
+    assert!(get_value_breakdown_internal(KeyValues::Str, &game)
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Deity(DeityTypes::Athena)));
+}
+
+#[test]
+fn test_new_deities() {
+    let mut game = faithful_game();
+    // Hades only answers from tier 5 on
+    choose_deity_internal(DeityTypes::Hades, &mut game);
+    assert_eq!(game.input.deity, None);
+
+    choose_deity_internal(DeityTypes::Hermes, &mut game);
+    game.state.items.divine_favor = 1e6;
+    engine_run(&mut game);
+    let dex_xp = game.intermediate_state.get_multiplier(KeyValues::Dex);
+    buy_blessing_internal(BlessingTypes::HermesSwiftness, &mut game);
+    engine_run(&mut game);
+    assert_eq!(level(&game, BlessingTypes::HermesSwiftness), 1);
+    let multiplier = game.intermediate_state.get_multiplier(KeyValues::Dex);
+    assert!((multiplier - dex_xp * 1.2).abs() < 1e-9);
+
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.unlocks.has_faith = true;
+    save.state.rebirth_stats.tier = 5;
+    let mut game = load(save);
+    engine_run(&mut game);
+    choose_deity_internal(DeityTypes::Hades, &mut game);
+    assert_eq!(game.input.deity, Some(DeityTypes::Hades));
+    engine_run(&mut game);
+    assert!(get_value_breakdown_internal(KeyValues::Health, &game)
+        .bases
+        .iter()
+        .any(|b| b.source == GainSource::Deity(DeityTypes::Hades) && b.amount == -1.0));
+}
+
+#[test]
+fn test_patron_carries_over() {
+    let mut game = faithful_game();
+    choose_deity_internal(DeityTypes::Poseidon, &mut game);
+    engine_run(&mut game);
+    character_death_update(&mut game);
+
+    do_rebirth_replay_internal(&mut game);
+    // The choice is replayed with the other inputs
+    engine_run(&mut game);
+    assert_eq!(game.input.deity, Some(DeityTypes::Poseidon));
+    character_death_update(&mut game);
+
+    // A new life keeps the patron, so auto-buy still finds blessings
+    do_rebirth_internal(&mut game);
+    assert_eq!(game.input.deity, Some(DeityTypes::Poseidon));
+    game.state.items.divine_favor = 1e6;
+    engine_run(&mut game);
+    auto_buy_blessing(&mut game);
+    assert!(level(&game, BlessingTypes::PoseidonsSturdiness) > 0);
+    character_death_update(&mut game);
+
+    // And records it, so a replay of that life has it too
+    do_rebirth_replay_internal(&mut game);
+    engine_run(&mut game);
+    assert_eq!(game.input.deity, Some(DeityTypes::Poseidon));
+}
+
+#[test]
+fn test_save_without_deity_loads() {
+    let mut game = faithful_game();
+    choose_deity_internal(DeityTypes::Afrodite, &mut game);
+    let save = serde_json::to_string(&GameSave::from(&game)).unwrap();
+    let game = load(serde_json::from_str(&save).unwrap());
+    assert_eq!(game.input.deity, Some(DeityTypes::Afrodite));
+
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    save["input"].as_object_mut().unwrap().remove("deity");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert_eq!(save.input.deity, None);
+}
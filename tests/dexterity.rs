@@ -0,0 +1,100 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::Game;
+use one_life::get_value_breakdown_internal;
+use one_life::input::activity::ActivityTypes;
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::stat::StatTypes;
+use one_life::input::work::WorkTypes;
+use one_life::util::run_until_dead;
+
+mod common;
+use common::load_tier;
+
+fn load_t7_carpenter(dex: f64) -> Game {
+    let mut game = load_tier(7);
+    game.meta_data.options.auto_work = false;
+    game.meta_data.options.auto_living = false;
+    game.meta_data.options.auto_buy_item = false;
+    game.input.work = WorkTypes::Carpenter;
+    // Dexterity only becomes visible after the first tick
+    engine_run(&mut game);
+    game.state.stats[StatTypes::Dex as usize].level = dex;
+    engine_run(&mut game);
+    game
+}
+
+#[test]
+fn test_dexterity_boosts_crafting_income() {
+    let clumsy = load_t7_carpenter(0.0);
+    let nimble = load_t7_carpenter(20.0);
+    assert!(nimble.state.stats[StatTypes::Dex as usize].is_visible);
+    let ratio = nimble.intermediate_state.get_value(KeyValues::Carpenter)
+        / clumsy.intermediate_state.get_value(KeyValues::Carpenter);
+    assert!((ratio - 3.0).abs() < 1e-9);
+    // Only crafting works are affected
+    assert_eq!(
+        nimble.intermediate_state.get_value(KeyValues::Farmer),
+        clumsy.intermediate_state.get_value(KeyValues::Farmer)
+    );
+    let breakdown = get_value_breakdown_internal(KeyValues::Carpenter, &nimble);
+    assert!(breakdown
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Stat(StatTypes::Dex)));
+}
+
+#[test]
+fn test_acrobatics_trains_dexterity() {
+    let mut game = load_t7_carpenter(0.0);
+    game.input.activity = ActivityTypes::Acrobatics;
+    for _ in 0..300 {
+        engine_run(&mut game);
+    }
+    assert!(game.state.stats[StatTypes::Dex as usize].level > 0.0);
+}
+
+#[test]
+fn test_dexterity_items() {
+    let mut game = load_t7_carpenter(0.0);
+    let dex_xp = game.intermediate_state.get_multiplier(KeyValues::Dex);
+    let soldier_xp = game.intermediate_state.get_multiplier(KeyValues::SoldierXp);
+    let income = game.intermediate_state.get_value(KeyValues::Carpenter);
+    for item in [
+        BoostItemTypes::JugglingBalls,
+        BoostItemTypes::FineTools,
+        BoostItemTypes::BalanceBeam,
+    ] {
+        game.state.boost_items[item as usize].is_purchased = true;
+    }
+    engine_run(&mut game);
+    assert_eq!(
+        game.intermediate_state.get_multiplier(KeyValues::Dex),
+        dex_xp * 2.0
+    );
+    assert_eq!(
+        game.intermediate_state.get_multiplier(KeyValues::SoldierXp),
+        soldier_xp * 1.5
+    );
+    assert!((game.intermediate_state.get_value(KeyValues::Carpenter) / income - 1.5).abs() < 1e-9);
+}
+
+// A carpenter with the same life but more Dexterity ends up richer
+#[test]
+fn test_dexterity_changes_a_tier_7_life() {
+    let mut clumsy = load_t7_carpenter(0.0);
+    let mut nimble = load_t7_carpenter(20.0);
+    run_until_dead(&mut clumsy);
+    run_until_dead(&mut nimble);
+    assert_eq!(
+        clumsy.state.life_stats.current_tick,
+        nimble.state.life_stats.current_tick
+    );
+    assert!(
+        nimble.state.items.money > clumsy.state.items.money * 2.0,
+        "Money with dexterity: {}, without: {}",
+        nimble.state.items.money,
+        clumsy.state.items.money
+    );
+}
@@ -0,0 +1,109 @@
+use one_life::buy_item_internal;
+use one_life::engine::engine_run;
+use one_life::events::{EventQueue, GameEventType};
+use one_life::game::{Game, GameSave};
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::housing::HousingTypes;
+use one_life::input::work::WorkTypes;
+use one_life::util::{run_until_dead, set_full_auto};
+
+mod common;
+use common::load;
+
+fn drain_types(game: &mut Game) -> Vec<GameEventType> {
+    game.events.drain().into_iter().map(|e| e.event).collect()
+}
+
+#[test]
+fn test_full_auto_life_events() {
+    let mut save = GameSave::default();
+    set_full_auto(&mut save.meta_data.options);
+    let mut game = load(save);
+    run_until_dead(&mut game);
+    let events = drain_types(&mut game);
+    assert!(events.contains(&GameEventType::WorkLevelUp {
+        work: WorkTypes::Mines,
+        level: 1
+    }));
+    assert!(events.contains(&GameEventType::WorkUnlocked {
+        work: WorkTypes::Latrine
+    }));
+    // Mines is there from the start of the life
+    assert!(!events.contains(&GameEventType::WorkUnlocked {
+        work: WorkTypes::Mines
+    }));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, GameEventType::ItemBought { auto: true, .. })));
+    assert!(matches!(
+        events.last(),
+        Some(GameEventType::LifeEnded { .. })
+    ));
+    assert!(game.events.is_empty());
+}
+
+#[test]
+fn test_hard_reset_drops_events() {
+    let mut game = load(GameSave::default());
+    game.push_event(GameEventType::ItemBought {
+        item: BoostItemTypes::Book,
+        auto: false,
+    });
+    game.hard_reset();
+    assert!(game.events.is_empty());
+}
+
+#[test]
+fn test_eviction_is_reported_once() {
+    let mut game = load(GameSave::default());
+    game.input.housing = HousingTypes::FilthyBarracks;
+    for _ in 0..10 {
+        engine_run(&mut game);
+    }
+    assert!(game.state.life_stats.evicted);
+    let evictions: Vec<GameEventType> = drain_types(&mut game)
+        .into_iter()
+        .filter(|e| matches!(e, GameEventType::Evicted { .. }))
+        .collect();
+    assert_eq!(
+        evictions,
+        vec![GameEventType::Evicted {
+            housing: HousingTypes::FilthyBarracks
+        }]
+    );
+
+    game.input.housing = HousingTypes::StoneFloor;
+    engine_run(&mut game);
+    assert!(!game.state.life_stats.evicted);
+}
+
+#[test]
+fn test_manual_purchase_event() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    game.state.items.money = 1e9;
+    game.events.drain();
+    buy_item_internal(BoostItemTypes::Book, &mut game);
+    let events = game.events.drain();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].tick, 1);
+    assert_eq!(
+        events[0].event,
+        GameEventType::ItemBought {
+            item: BoostItemTypes::Book,
+            auto: false
+        }
+    );
+}
+
+#[test]
+fn test_queue_drops_oldest_events() {
+    let mut queue = EventQueue::new();
+    for tick in 0..1500 {
+        queue.push(tick, GameEventType::TierBought { tier: 1 });
+    }
+    assert_eq!(queue.len(), 1000);
+    let events = queue.drain();
+    assert_eq!(events[0].tick, 500);
+    assert!(queue.is_empty());
+}
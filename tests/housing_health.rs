@@ -0,0 +1,75 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::{Game, GameSave};
+use one_life::get_value_breakdown_internal;
+use one_life::input::housing::HousingTypes;
+use one_life::presets::get_presets;
+use one_life::util::{run_until_dead, set_full_auto};
+
+mod common;
+use common::load;
+
+fn death_age_in_years(save: GameSave) -> u32 {
+    let mut game = load(save);
+    run_until_dead(&mut game);
+    (game.state.life_stats.age / 365.0).round() as u32
+}
+
+fn rich_life_in(housing: HousingTypes) -> Game {
+    let mut save = GameSave::default();
+    save.state.items.money = 1e12;
+    let mut game = load(save);
+    engine_run(&mut game);
+    game.input.housing = housing;
+    engine_run(&mut game);
+    game
+}
+
+#[test]
+fn test_housing_health_in_breakdown() {
+    let game = rich_life_in(HousingTypes::Apartment);
+    let apartment = game.world.get_housing(HousingTypes::Apartment);
+    let breakdown = get_value_breakdown_internal(KeyValues::Health, &game);
+    let housing = breakdown
+        .bases
+        .iter()
+        .find(|b| b.source == GainSource::Housing(HousingTypes::Apartment))
+        .unwrap();
+    assert_eq!(housing.amount, apartment.health_effect);
+    assert_eq!(housing.amount, 0.8);
+}
+
+#[test]
+fn test_better_housing_lives_longer() {
+    let mut stone_floor = rich_life_in(HousingTypes::StoneFloor);
+    let mut apartment = rich_life_in(HousingTypes::Apartment);
+    assert!(apartment.state.life_stats.health_rate > stone_floor.state.life_stats.health_rate);
+    run_until_dead(&mut stone_floor);
+    run_until_dead(&mut apartment);
+    assert_eq!(stone_floor.input.housing, HousingTypes::StoneFloor);
+    assert_eq!(apartment.input.housing, HousingTypes::Apartment);
+    assert!(apartment.state.life_stats.age > stone_floor.state.life_stats.age * 1.1);
+}
+
+// Housing changes how long a life lasts. If one of these changes, check that it
+// was meant to and update the expected age.
+#[test]
+fn test_preset_lifespans() {
+    let mut fresh = GameSave::default();
+    set_full_auto(&mut fresh.meta_data.options);
+    assert_eq!(death_age_in_years(fresh), 37);
+
+    let mut presets = get_presets();
+    let expected = [
+        ("01: T1 expected", 37),
+        ("05: T5 expected", 48),
+        ("T0 Test_1: Re 2", 42),
+        ("T2 Test_5: Re 15", 72),
+        ("T4 Test_3: Re 36", 113),
+    ];
+    for (name, age) in expected {
+        let save = presets.remove(name).unwrap();
+        assert_eq!(death_age_in_years(save), age, "{}", name);
+    }
+}
@@ -0,0 +1,56 @@
+use one_life::engine::engine_run;
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::work::{WorkCategoryTypes, WorkTypes};
+use one_life::presets::{make_t4, make_t5_intellectual};
+use one_life::util::run_until_dead;
+
+mod common;
+use common::load;
+
+#[test]
+fn test_intellectual_works_need_tier_5() {
+    let mut game = load(make_t4());
+    engine_run(&mut game);
+    let servant = &game.state.works[WorkTypes::Servant as usize];
+    assert!(!servant.is_visible);
+    assert!(!servant.is_unlocked);
+    assert_eq!(
+        game.world.get_work(WorkTypes::Servant).work_type,
+        WorkCategoryTypes::Intellectual
+    );
+}
+
+#[test]
+fn test_intellectual_unlock_chain() {
+    let mut game = load(make_t5_intellectual());
+    game.meta_data.options.auto_work = false;
+    engine_run(&mut game);
+    assert!(game.state.works[WorkTypes::Servant as usize].is_unlocked);
+    assert!(!game.state.works[WorkTypes::Teacher as usize].is_unlocked);
+    // Librarian needs tier 6
+    assert!(!game.state.works[WorkTypes::Librarian as usize].is_visible);
+
+    game.state.works[WorkTypes::Servant as usize].level = 10;
+    engine_run(&mut game);
+    assert!(game.state.works[WorkTypes::Teacher as usize].is_unlocked);
+    assert!(!game.state.works[WorkTypes::Scribe as usize].is_unlocked);
+}
+
+#[test]
+fn test_t5_intellectual_life() {
+    let mut game = load(make_t5_intellectual());
+    run_until_dead(&mut game);
+    let works = &game.state.works;
+    assert_eq!(game.input.work, WorkTypes::Scribe);
+    assert!(
+        works[WorkTypes::Scribe as usize].level >= 15,
+        "Scribe level: {}",
+        works[WorkTypes::Scribe as usize].level
+    );
+    assert!(
+        game.state.boost_items[BoostItemTypes::Quill as usize].is_purchased,
+        "No quill purchased, money: {}",
+        game.state.items.money
+    );
+    assert!(game.state.rebirth_stats.max_job_levels[WorkTypes::Scribe as usize] >= 15);
+}
@@ -0,0 +1,48 @@
+use one_life::engine::engine_run;
+use one_life::presets::get_presets;
+
+mod common;
+use common::load;
+
+// Runs the preset twice, once with the cached modifiers and once rebuilding them
+// every tick, and checks that both runs are identical
+fn assert_cache_matches_full_recompute(preset: &str) {
+    let save = get_presets().remove(preset).unwrap();
+    let mut cached = load(save.clone());
+    let mut full = load(save);
+    while !cached.state.life_stats.is_dying {
+        engine_run(&mut cached);
+        full.intermediate_state.invalidate();
+        engine_run(&mut full);
+
+        let tick = cached.state.life_stats.current_tick;
+        assert_eq!(
+            cached.intermediate_state.value_gains, full.intermediate_state.value_gains,
+            "modifiers differ on tick {}",
+            tick
+        );
+        if tick.is_multiple_of(1000) {
+            assert_eq!(
+                serde_json::to_string(&cached.state).unwrap(),
+                serde_json::to_string(&full.state).unwrap(),
+                "state differs on tick {}",
+                tick
+            );
+        }
+    }
+    assert!(full.state.life_stats.is_dying);
+    assert_eq!(
+        serde_json::to_string(&cached.state).unwrap(),
+        serde_json::to_string(&full.state).unwrap()
+    );
+}
+
+#[test]
+fn test_cache_matches_full_recompute_with_replay() {
+    assert_cache_matches_full_recompute("T2 Test_5: Re 15");
+}
+
+#[test]
+fn test_cache_matches_full_recompute_t5() {
+    assert_cache_matches_full_recompute("05: T5 expected");
+}
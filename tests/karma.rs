@@ -0,0 +1,108 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::events::GameEventType;
+use one_life::game::{Game, GameSave};
+use one_life::input::karma_upgrade::KarmaUpgradeTypes;
+use one_life::presets::get_presets;
+use one_life::util::run_until_dead;
+use one_life::world_content::karma_upgrade::calculate_karma_gain;
+use one_life::{buy_karma_upgrade_internal, do_rebirth_internal, get_value_breakdown_internal};
+
+mod common;
+use common::load;
+
+fn karma_gain_at_death(game: &mut Game) -> f64 {
+    game.events
+        .drain()
+        .into_iter()
+        .find_map(|e| match e.event {
+            GameEventType::LifeEnded { karma_gain, .. } => Some(karma_gain),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_faith_life_earns_karma() {
+    let mut game = load(get_presets().remove("06: T5 Faith").unwrap());
+    let karma = game.state.rebirth_stats.karma;
+    run_until_dead(&mut game);
+    let karma_gain = karma_gain_at_death(&mut game);
+    assert!(karma_gain > 0.0);
+    assert_eq!(karma_gain, calculate_karma_gain(&game.state));
+    assert_eq!(game.state.rebirth_stats.karma, karma + karma_gain);
+
+    // Karma is kept through rebirth
+    do_rebirth_internal(&mut game);
+    assert_eq!(game.state.rebirth_stats.karma, karma + karma_gain);
+}
+
+#[test]
+fn test_life_without_faith_earns_no_karma() {
+    let mut game = load(GameSave::default());
+    run_until_dead(&mut game);
+    assert_eq!(karma_gain_at_death(&mut game), 0.0);
+    assert_eq!(game.state.rebirth_stats.karma, 0.0);
+}
+
+#[test]
+fn test_buy_karma_upgrade() {
+    let mut game = load(get_presets().remove("06: T5 Faith").unwrap());
+    game.state.rebirth_stats.karma = 2.0;
+    engine_run(&mut game);
+    let happiness = game.intermediate_state.get_multiplier(KeyValues::Happiness);
+
+    // Too expensive
+    buy_karma_upgrade_internal(KarmaUpgradeTypes::Devotion, &mut game);
+    assert!(
+        !game.state.rebirth_stats.karma_upgrades[KarmaUpgradeTypes::Devotion as usize].is_purchased
+    );
+    assert_eq!(game.state.rebirth_stats.karma, 2.0);
+
+    buy_karma_upgrade_internal(KarmaUpgradeTypes::InnerPeace, &mut game);
+    assert!(
+        game.state.rebirth_stats.karma_upgrades[KarmaUpgradeTypes::InnerPeace as usize]
+            .is_purchased
+    );
+    assert_eq!(game.state.rebirth_stats.karma, 1.0);
+    // Can't be bought twice
+    buy_karma_upgrade_internal(KarmaUpgradeTypes::InnerPeace, &mut game);
+    assert_eq!(game.state.rebirth_stats.karma, 1.0);
+
+    engine_run(&mut game);
+    assert_eq!(
+        game.intermediate_state.get_multiplier(KeyValues::Happiness),
+        happiness * 1.5
+    );
+    let breakdown = get_value_breakdown_internal(KeyValues::Happiness, &game);
+    assert!(breakdown
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::KarmaUpgrade(KarmaUpgradeTypes::InnerPeace)));
+}
+
+#[test]
+fn test_virtue_increases_karma_gain() {
+    let mut game = load(get_presets().remove("06: T5 Faith").unwrap());
+    game.state.rebirth_stats.karma_upgrades[KarmaUpgradeTypes::Virtue as usize].is_purchased = true;
+    run_until_dead(&mut game);
+    let karma_gain = karma_gain_at_death(&mut game);
+    assert_eq!(karma_gain, calculate_karma_gain(&game.state) * 1.5);
+}
+
+#[test]
+fn test_save_without_karma_upgrades_loads() {
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    save["state"]["rebirth_stats"]
+        .as_object_mut()
+        .unwrap()
+        .remove("karma_upgrades");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert!(save
+        .state
+        .rebirth_stats
+        .karma_upgrades
+        .iter()
+        .all(|u| !u.is_purchased));
+}
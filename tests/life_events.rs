@@ -0,0 +1,167 @@
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::engine::{character_death_update, engine_run};
+use one_life::events::GameEventType;
+use one_life::game::{Game, GameSave};
+use one_life::input::life_event::LifeEventTypes;
+use one_life::input::work::WorkTypes;
+use one_life::state::rng::Rng;
+use one_life::util::set_full_auto;
+use one_life::world_content::life_event::{LIFE_EVENT_INTERVAL, LIFE_EVENT_TIER};
+use one_life::{do_rebirth_internal, do_rebirth_replay_internal, get_value_breakdown_internal};
+
+mod common;
+use common::load;
+
+fn full_auto_game() -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = LIFE_EVENT_TIER;
+    set_full_auto(&mut save.meta_data.options);
+    load(save)
+}
+
+// Runs the life to its end and returns the life events with their tick
+fn life_events_until_dead(game: &mut Game) -> Vec<(u32, LifeEventTypes)> {
+    let mut life_events = vec![];
+    while !game.state.life_stats.is_dying {
+        engine_run(game);
+        for event in game.events.drain() {
+            if let GameEventType::LifeEventHappened { life_event } = event.event {
+                life_events.push((event.tick, life_event));
+            }
+        }
+    }
+    character_death_update(game);
+    life_events
+}
+
+#[test]
+fn test_rng_is_seeded() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let a: Vec<u32> = (0..10).map(|_| a.next_u32()).collect();
+    let b: Vec<u32> = (0..10).map(|_| b.next_u32()).collect();
+    let c: Vec<u32> = (0..10).map(|_| c.next_u32()).collect();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+
+    let mut rng = Rng::new(7);
+    for _ in 0..1000 {
+        let value = rng.next_f64();
+        assert!((0.0..1.0).contains(&value));
+    }
+}
+
+#[test]
+fn test_life_events_happen_on_rolls() {
+    let mut game = full_auto_game();
+    let life_events = life_events_until_dead(&mut game);
+    assert!(!life_events.is_empty());
+    for (tick, _) in life_events.iter() {
+        assert_eq!(tick % LIFE_EVENT_INTERVAL, 0);
+    }
+    let times_happened: u32 = game
+        .state
+        .life_events
+        .iter()
+        .map(|e| e.times_happened)
+        .sum();
+    assert_eq!(times_happened as usize, life_events.len());
+}
+
+#[test]
+fn test_no_life_events_below_their_tier() {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = LIFE_EVENT_TIER - 1;
+    set_full_auto(&mut save.meta_data.options);
+    let mut game = load(save);
+    assert!(life_events_until_dead(&mut game).is_empty());
+}
+
+#[test]
+fn test_same_seed_same_life_events() {
+    let mut first = full_auto_game();
+    let mut second = full_auto_game();
+    assert_eq!(first.state.rng, second.state.rng);
+    assert_eq!(
+        life_events_until_dead(&mut first),
+        life_events_until_dead(&mut second)
+    );
+}
+
+#[test]
+fn test_replay_keeps_the_seed() {
+    let mut game = full_auto_game();
+    let first_life = life_events_until_dead(&mut game);
+    let first_life_ticks = game.state.life_stats.current_tick;
+    let seed = game.state.rng.seed;
+
+    do_rebirth_replay_internal(&mut game);
+    assert!(game.state.life_stats.replaying);
+    assert_eq!(game.state.rng.seed, seed);
+    let replayed = life_events_until_dead(&mut game);
+    // The higher max job levels make the lives last a bit differently, the rolls
+    // they share are the same
+    let ticks = first_life_ticks.min(game.state.life_stats.current_tick);
+    let shared = |life_events: Vec<(u32, LifeEventTypes)>| -> Vec<(u32, LifeEventTypes)> {
+        life_events
+            .into_iter()
+            .filter(|(t, _)| *t <= ticks)
+            .collect()
+    };
+    assert!(replayed.len() > 1);
+    assert_eq!(shared(replayed), shared(first_life));
+
+    // A new life gets a new seed
+    do_rebirth_internal(&mut game);
+    assert_ne!(game.state.rng.seed, seed);
+}
+
+#[test]
+fn test_life_event_effects() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    let income = game.intermediate_state.get_value(WorkTypes::Mines.into());
+    let happiness = game.intermediate_state.get_multiplier(KeyValues::Happiness);
+
+    let tick = game.state.life_stats.current_tick;
+    for life_event in [LifeEventTypes::Windfall, LifeEventTypes::Festival] {
+        game.state.life_events[life_event as usize].active_until = tick + 10;
+    }
+    engine_run(&mut game);
+    assert_eq!(
+        game.intermediate_state.get_value(WorkTypes::Mines.into()),
+        income * 2.0
+    );
+    assert_eq!(
+        game.intermediate_state.get_multiplier(KeyValues::Happiness),
+        happiness * 1.5
+    );
+    let breakdown = get_value_breakdown_internal(KeyValues::Happiness, &game);
+    assert!(breakdown
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::LifeEvent(LifeEventTypes::Festival)));
+
+    // And they wear off
+    for _ in 0..10 {
+        engine_run(&mut game);
+    }
+    assert!(!game.state.life_events[LifeEventTypes::Windfall as usize].is_active);
+    assert_eq!(
+        game.intermediate_state.get_value(WorkTypes::Mines.into()),
+        income
+    );
+}
+
+#[test]
+fn test_save_without_life_events_loads() {
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    let state = save["state"].as_object_mut().unwrap();
+    state.remove("life_events");
+    state.remove("rng");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert_eq!(save.state.rng, Rng::default());
+    assert!(save.state.life_events.iter().all(|e| !e.is_active));
+}
@@ -0,0 +1,81 @@
+use one_life::do_rebirth_internal;
+use one_life::game::GameSave;
+use one_life::get_life_history_internal;
+use one_life::input::work::{WorkCategoryTypes, WorkTypes};
+use one_life::state::life_history::{LifeHistory, LifeSummary, MAX_LIFE_HISTORY};
+use one_life::state::state_container::StateContainer;
+use one_life::util::{run_until_dead, set_full_auto};
+
+mod common;
+use common::load;
+
+#[test]
+fn test_life_recorded_at_death() {
+    let mut save = GameSave::default();
+    set_full_auto(&mut save.meta_data.options);
+    let mut game = load(save);
+    run_until_dead(&mut game);
+
+    let history = get_life_history_internal(&game);
+    assert_eq!(history.len(), 1);
+    let life = &history[0];
+    assert_eq!(life.rebirth_count, 0);
+    assert_eq!(life.tier, 0);
+    assert_eq!(life.age, game.state.life_stats.age);
+    assert_eq!(life.ticks, game.state.life_stats.current_tick);
+    assert_eq!(life.coins_gain, game.state.rebirth_stats.coins);
+    assert!(life.max_money > 0.0);
+    assert!(life.max_money >= game.state.items.money);
+    let labor = life
+        .highest_jobs
+        .iter()
+        .find(|job| job.category == WorkCategoryTypes::Labor)
+        .unwrap();
+    assert!(labor.work >= WorkTypes::Mines);
+    assert_eq!(labor.level, game.state.works[labor.work as usize].level);
+    assert_eq!(
+        life.items_bought as usize,
+        game.state
+            .boost_items
+            .iter()
+            .filter(|i| i.is_purchased)
+            .count()
+    );
+
+    // The history survives rebirth and the next death adds to it
+    do_rebirth_internal(&mut game);
+    assert_eq!(get_life_history_internal(&game).len(), 1);
+    run_until_dead(&mut game);
+    let history = get_life_history_internal(&game);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].rebirth_count, 1);
+}
+
+#[test]
+fn test_life_history_is_bounded() {
+    let state = StateContainer::default();
+    let mut history = LifeHistory::default();
+    for rebirth_count in 0..(MAX_LIFE_HISTORY as u32 + 5) {
+        let mut summary = LifeSummary::new(&state, 0.0);
+        summary.rebirth_count = rebirth_count;
+        history.push(summary);
+    }
+    assert_eq!(history.len(), MAX_LIFE_HISTORY);
+    assert_eq!(history.lives()[0].rebirth_count, 5);
+}
+
+#[test]
+fn test_save_without_history_loads() {
+    let mut save = serde_json::to_value(GameSave::default()).unwrap();
+    save["state"]["rebirth_stats"]
+        .as_object_mut()
+        .unwrap()
+        .remove("history");
+    save["state"]["items"]
+        .as_object_mut()
+        .unwrap()
+        .remove("max_money");
+    let save: GameSave = serde_json::from_value(save).unwrap();
+    assert!(save.state.rebirth_stats.history.is_empty());
+    assert_eq!(save.state.items.max_money, 0.0);
+}
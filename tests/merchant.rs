@@ -0,0 +1,109 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::{Game, GameSave};
+use one_life::input::stat::StatTypes;
+use one_life::input::work::{WorkCategoryTypes, WorkTypes};
+use one_life::{get_value_breakdown_internal, set_work_internal};
+
+mod common;
+use common::load;
+
+fn trader_game() -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = 6;
+    let mut game = load(save);
+    engine_run(&mut game);
+    set_work_internal(WorkTypes::Trader, &mut game);
+    engine_run(&mut game);
+    game
+}
+
+// Trader income on the next tick when holding money
+fn trader_income(game: &mut Game, money: f64) -> f64 {
+    game.state.items.money = money;
+    engine_run(game);
+    game.intermediate_state.get_value(KeyValues::Trader)
+}
+
+#[test]
+fn test_merchant_works_need_tier_6() {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = 5;
+    let mut game = load(save);
+    engine_run(&mut game);
+    assert!(!game.state.works[WorkTypes::Trader as usize].is_visible);
+
+    let game = trader_game();
+    assert!(game.state.works[WorkTypes::Trader as usize].is_unlocked);
+    assert_eq!(game.input.work, WorkTypes::Trader);
+    assert_eq!(
+        game.world.get_work(WorkTypes::Trader).work_type,
+        WorkCategoryTypes::Merchant
+    );
+    // Merchant needs tier 7
+    assert!(!game.state.works[WorkTypes::Merchant as usize].is_visible);
+}
+
+#[test]
+fn test_merchant_unlock_chain() {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = 7;
+    let mut game = load(save);
+    engine_run(&mut game);
+    assert!(!game.state.works[WorkTypes::Merchant as usize].is_unlocked);
+    game.state.works[WorkTypes::Trader as usize].level = 10;
+    engine_run(&mut game);
+    assert!(game.state.works[WorkTypes::Merchant as usize].is_unlocked);
+}
+
+#[test]
+fn test_trader_income_scales_with_money() {
+    let mut game = trader_game();
+    let flat = trader_income(&mut game, 0.0);
+    let income = trader_income(&mut game, 1e6);
+    let double_income = trader_income(&mut game, 2e6);
+    assert!(flat > 0.0);
+    assert!(income > flat);
+    assert!((double_income - flat - 2.0 * (income - flat)).abs() < 1e-6);
+
+    let breakdown = get_value_breakdown_internal(KeyValues::Trader, &game);
+    let commission = breakdown
+        .bases
+        .iter()
+        .find(|b| b.source == GainSource::Commission(WorkTypes::Trader))
+        .unwrap();
+    let trader = game.world.get_work(WorkTypes::Trader);
+    assert_eq!(commission.amount, trader.commission * 2e6);
+    assert_eq!(commission.display_name, "Trader commission");
+    // The income is paid out as money
+    assert_eq!(game.state.items.income, double_income);
+}
+
+#[test]
+fn test_trader_income_scales_with_charisma() {
+    let mut game = trader_game();
+    let income = trader_income(&mut game, 1e6);
+    let cha = game.state.stats[StatTypes::Cha as usize].level;
+    game.state.stats[StatTypes::Cha as usize].level = cha + 10.0;
+    let charming_income = trader_income(&mut game, 1e6);
+    let expected = income * (1.0 + (cha + 10.0) / 10.0) / (1.0 + cha / 10.0);
+    assert!((charming_income - expected).abs() < 1e-6);
+
+    let breakdown = get_value_breakdown_internal(KeyValues::Trader, &game);
+    assert!(breakdown
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Stat(StatTypes::Cha)));
+}
+
+#[test]
+fn test_labor_income_ignores_money() {
+    let mut game = trader_game();
+    game.state.items.money = 0.0;
+    engine_run(&mut game);
+    let mill = game.intermediate_state.get_value(KeyValues::Mill);
+    game.state.items.money = 1e6;
+    engine_run(&mut game);
+    assert_eq!(game.intermediate_state.get_value(KeyValues::Mill), mill);
+}
@@ -0,0 +1,109 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::{Game, GameSave};
+use one_life::get_value_breakdown_internal;
+use one_life::input::skill::SkillTypes;
+use one_life::input::work::{WorkCategoryTypes, WorkTypes};
+
+mod common;
+use common::load;
+
+const UPPER_RANKS: [(WorkTypes, f64, f64); 5] = [
+    (WorkTypes::ThessalianCavalry, 10.0, 1.2),
+    (WorkTypes::CompanionCavalry, 20.0, 1.4),
+    (WorkTypes::SquadCommander, 30.0, 1.6),
+    (WorkTypes::Commander, 40.0, 1.8),
+    (WorkTypes::General, 50.0, 2.0),
+];
+
+fn load_tier(tier: u32) -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = tier;
+    save.state.rebirth_stats.unlocks.has_military_tactics = true;
+    let mut game = load(save);
+    game.meta_data.options.auto_work = false;
+    game
+}
+
+fn is_unlocked(game: &Game, work: WorkTypes) -> bool {
+    game.state.works[work as usize].is_unlocked
+}
+
+#[test]
+fn test_upper_ranks_are_soldier_works() {
+    let game = load_tier(0);
+    for (work, _, _) in UPPER_RANKS {
+        assert_eq!(
+            game.world.get_work(work).work_type,
+            WorkCategoryTypes::Soldier
+        );
+    }
+}
+
+#[test]
+fn test_upper_ranks_need_tactics() {
+    let mut game = load_tier(7);
+    let mut previous = WorkTypes::LightCavalry;
+    for (work, tactics, _) in UPPER_RANKS {
+        game.state.works[previous as usize].level = 10;
+        game.state.skills[SkillTypes::Tactics as usize].level = tactics - 1.0;
+        engine_run(&mut game);
+        assert!(game.state.works[work as usize].is_visible);
+        assert!(!is_unlocked(&game, work), "{:?} unlocked", work);
+
+        game.state.skills[SkillTypes::Tactics as usize].level = tactics;
+        engine_run(&mut game);
+        assert!(is_unlocked(&game, work), "{:?} locked", work);
+        previous = work;
+    }
+}
+
+#[test]
+fn test_upper_ranks_need_the_previous_rank() {
+    let mut game = load_tier(7);
+    game.state.skills[SkillTypes::Tactics as usize].level = 100.0;
+    engine_run(&mut game);
+    for (work, _, _) in UPPER_RANKS {
+        assert!(!is_unlocked(&game, work), "{:?} unlocked", work);
+    }
+    game.state.works[WorkTypes::LightCavalry as usize].level = 10;
+    engine_run(&mut game);
+    assert!(is_unlocked(&game, WorkTypes::ThessalianCavalry));
+    assert!(!is_unlocked(&game, WorkTypes::CompanionCavalry));
+}
+
+#[test]
+fn test_upper_ranks_are_tier_gated() {
+    let mut game = load_tier(5);
+    engine_run(&mut game);
+    let works = &game.state.works;
+    assert!(works[WorkTypes::ThessalianCavalry as usize].is_visible);
+    assert!(works[WorkTypes::CompanionCavalry as usize].is_visible);
+    assert!(!works[WorkTypes::SquadCommander as usize].is_visible);
+    assert!(!works[WorkTypes::General as usize].is_visible);
+
+    let mut game = load_tier(6);
+    engine_run(&mut game);
+    assert!(game.state.works[WorkTypes::Commander as usize].is_visible);
+    assert!(!game.state.works[WorkTypes::General as usize].is_visible);
+}
+
+#[test]
+fn test_upper_ranks_boost_tactics_xp() {
+    let mut game = load_tier(7);
+    game.input.work = WorkTypes::LightCavalry;
+    engine_run(&mut game);
+    let tactics = game.intermediate_state.get_multiplier(KeyValues::Tactics);
+    for (work, _, boost) in UPPER_RANKS {
+        game.input.work = work;
+        engine_run(&mut game);
+        let multiplier = game.intermediate_state.get_multiplier(KeyValues::Tactics);
+        assert!((multiplier - tactics * boost).abs() < 1e-9);
+        let breakdown = get_value_breakdown_internal(KeyValues::Tactics, &game);
+        assert!(breakdown
+            .multipliers
+            .iter()
+            .any(|m| m.source == GainSource::Work(work)));
+    }
+}
@@ -0,0 +1,144 @@
+use one_life::engine::engine_run;
+use one_life::engine::projection::{predicted_death_age, project, ProjectionTarget};
+use one_life::game::{Game, GameSave};
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::housing::HousingTypes;
+use one_life::input::stat::StatTypes;
+use one_life::input::work::WorkTypes;
+use one_life::presets::get_presets;
+use one_life::util::{run_until_dead, set_full_auto};
+use one_life::TICK_RATE;
+use strum::IntoEnumIterator;
+
+mod common;
+use common::load;
+
+// Steps the game one tick at a time until reached, checking that the projection
+// counts down one tick per tick whenever nothing happened that changes the rates
+fn assert_projection_counts_down(
+    game: &mut Game,
+    target: ProjectionTarget,
+    reached: impl Fn(&Game) -> bool,
+) {
+    let mut projected = project(game, target).unwrap().ticks;
+    let mut checked = 0;
+    let mut hit = false;
+    for _ in 0..100_000 {
+        game.events.drain();
+        let gains = game.intermediate_state.value_gains.clone();
+        engine_run(game);
+        let rates_changed = !game.events.is_empty() || game.intermediate_state.value_gains != gains;
+        if !rates_changed {
+            checked += 1;
+            // Money and XP add up in floating point, so allow a tick either way
+            if reached(game) {
+                assert!(projected <= 2, "reached {} ticks early", projected - 1);
+            } else {
+                let ticks = project(game, target).unwrap().ticks;
+                assert!(
+                    ticks.abs_diff(projected - 1) <= 1,
+                    "{} after {}",
+                    ticks,
+                    projected
+                );
+            }
+        }
+        if reached(game) {
+            hit = true;
+            break;
+        }
+        projected = project(game, target).unwrap().ticks;
+    }
+    assert!(hit);
+    assert!(checked > 10);
+}
+
+#[test]
+fn test_time_to_work_level() {
+    let mut game = load(GameSave::default());
+    for _ in 0..10 {
+        engine_run(&mut game);
+    }
+    let work = game.input.work;
+    let level = game.state.works[work as usize].level;
+    let projection = project(&game, ProjectionTarget::Work(work)).unwrap();
+    let game_speed = game.meta_data.game_speed as f64;
+    assert_eq!(
+        projection.seconds,
+        projection.ticks as f64 / TICK_RATE / game_speed
+    );
+    assert_projection_counts_down(&mut game, ProjectionTarget::Work(work), |game| {
+        game.state.works[work as usize].level > level
+    });
+
+    // Other works don't gain XP
+    assert!(project(&game, ProjectionTarget::Work(WorkTypes::Bishop)).is_none());
+}
+
+#[test]
+fn test_time_to_stat_level() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    let stat = StatTypes::from(game.world.get_work(game.input.work).work_type);
+    let level = game.state.stats[stat as usize].level;
+    assert_projection_counts_down(&mut game, ProjectionTarget::Stat(stat), |game| {
+        game.state.stats[stat as usize].level > level
+    });
+}
+
+#[test]
+fn test_time_to_afford() {
+    let mut game = load(GameSave::default());
+    engine_run(&mut game);
+    let item = BoostItemTypes::iter().next().unwrap();
+    let cost = game.world.boost_items[item as usize].purchasing_cost;
+    assert!(game.state.items.money < cost);
+    assert_projection_counts_down(&mut game, ProjectionTarget::BoostItem(item), |game| {
+        game.state.items.money >= cost
+    });
+    assert_eq!(
+        project(&game, ProjectionTarget::BoostItem(item))
+            .unwrap()
+            .ticks,
+        0
+    );
+
+    // Money only goes down while the upkeep is higher than the income
+    let mut game = load(GameSave::default());
+    game.state.items.money = 5.0e6;
+    game.input.housing = HousingTypes::Apartment;
+    engine_run(&mut game);
+    let target = ProjectionTarget::Housing(HousingTypes::Apartment);
+    assert_eq!(project(&game, target).unwrap().ticks, 0);
+    game.state.items.money = 3.9e6;
+    assert!(project(&game, target).is_none());
+}
+
+#[test]
+fn test_predicted_death_age() {
+    let mut save = get_presets().remove("05: T5 expected").unwrap();
+    set_full_auto(&mut save.meta_data.options);
+    let mut game = load(save);
+    let mut predictions = vec![];
+    while !game.state.life_stats.is_dying {
+        engine_run(&mut game);
+        if game.state.life_stats.current_tick.is_multiple_of(1000) {
+            predictions.push(predicted_death_age(&game));
+        }
+    }
+    let predicted_last = predicted_death_age(&game);
+    run_until_dead(&mut game);
+    let age = game.state.life_stats.age;
+    assert_eq!(predicted_last, age);
+    // Closer to the end the prediction gets more accurate
+    let last = *predictions.last().unwrap();
+    assert!((last - age).abs() / age < 0.001, "{} vs {}", last, age);
+    for prediction in predictions.iter().skip(predictions.len() / 2) {
+        assert!(
+            (prediction - age).abs() / age < 0.05,
+            "{} vs {}",
+            prediction,
+            age
+        );
+    }
+}
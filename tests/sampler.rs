@@ -0,0 +1,58 @@
+use one_life::do_rebirth_internal;
+use one_life::engine::engine_run;
+use one_life::game::GameSave;
+use one_life::sampler::{Sampler, MAX_SAMPLES};
+use one_life::state::state_container::StateContainer;
+use one_life::util::{run_until_dead, set_full_auto};
+
+mod common;
+use common::load;
+
+#[test]
+fn test_samples_follow_the_life() {
+    let mut save = GameSave::default();
+    set_full_auto(&mut save.meta_data.options);
+    let mut game = load(save);
+    game.sampler.set_interval(10);
+    for _ in 0..100 {
+        engine_run(&mut game);
+    }
+    let ticks: Vec<u32> = game.sampler.samples().iter().map(|s| s.tick).collect();
+    assert_eq!(ticks[0], 1);
+    assert_eq!(ticks[1..], (1..=10).map(|i| i * 10).collect::<Vec<u32>>());
+
+    run_until_dead(&mut game);
+    let samples = game.sampler.samples();
+    assert!(samples.len() <= MAX_SAMPLES);
+    assert!(samples.windows(2).all(|w| w[0].tick < w[1].tick));
+    assert!(samples.iter().any(|s| s.money > 0.0));
+    assert!(samples.iter().any(|s| s.income > 0.0));
+    assert!(samples.iter().all(|s| s.happiness > 0.0));
+    let last = samples.last().unwrap();
+    assert!(game.state.life_stats.current_tick - last.tick < game.sampler.interval());
+
+    // A new life starts a new series
+    do_rebirth_internal(&mut game);
+    let samples = game.sampler.samples();
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].tick, 1);
+}
+
+#[test]
+fn test_downsampling_keeps_series_bounded() {
+    let mut state = StateContainer::default();
+    let mut sampler = Sampler::new(1);
+    for tick in 1..=(10 * MAX_SAMPLES as u32) {
+        state.life_stats.current_tick = tick;
+        state.items.money = tick as f64;
+        sampler.record(&state);
+        assert!(sampler.samples().len() <= MAX_SAMPLES);
+    }
+    assert_eq!(sampler.interval(), 16);
+    let samples = sampler.samples();
+    assert!(samples.len() > MAX_SAMPLES / 2);
+    assert!(samples
+        .windows(2)
+        .all(|w| w[1].tick - w[0].tick <= sampler.interval()));
+    assert!(samples.iter().all(|s| s.money == s.tick as f64));
+}
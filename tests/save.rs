@@ -0,0 +1,126 @@
+use one_life::do_rebirth_internal;
+use one_life::engine::engine_run;
+use one_life::game::GameSave;
+use one_life::input::blessing::BlessingTypes;
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::rebirth_upgrade::RebirthUpgradeTypes;
+use one_life::input::skill::SkillTypes;
+use one_life::input::tomb::TombTypes;
+use one_life::input::work::WorkTypes;
+use one_life::presets::rebirth_15;
+use one_life::state::rebirth_upgrade::RebirthUpgrade;
+use one_life::wasm_api::meta::{decode_save, encode_save};
+use strum::IntoEnumIterator;
+
+mod common;
+use common::load;
+
+#[test]
+fn test_save_round_trip() {
+    let save = rebirth_15();
+    let decoded = decode_save(&encode_save(&save)).unwrap();
+    assert_eq!(
+        decoded.state.rebirth_stats.max_job_levels[WorkTypes::Fisherman as usize],
+        save.state.rebirth_stats.max_job_levels[WorkTypes::Fisherman as usize]
+    );
+    assert_eq!(decoded.state.rebirth_stats.tier, 2);
+    assert_eq!(
+        decoded.previous_inputs.mapping.len(),
+        save.previous_inputs.mapping.len()
+    );
+}
+
+#[test]
+fn test_invalid_save_is_error() {
+    assert!(decode_save("not a save").is_err());
+    assert!(decode_save(&encode_save(&GameSave::default())).is_ok());
+}
+
+#[test]
+fn test_save_with_fewer_rebirth_upgrades_loads() {
+    let mut save = rebirth_15();
+    save.state.rebirth_stats.rebirth_upgrades[0].is_purchased = true;
+    let mut json = serde_json::to_value(&save).unwrap();
+    // Saves from before the Borrowed Time upgrades were added
+    json["state"]["rebirth_stats"]["rebirth_upgrades"]
+        .as_array_mut()
+        .unwrap()
+        .truncate(RebirthUpgradeTypes::BorrowedTime1 as usize);
+    let loaded: GameSave = serde_json::from_value(json).unwrap();
+    let upgrades = &loaded.state.rebirth_stats.rebirth_upgrades;
+    assert!(upgrades[0].is_purchased);
+    for upgrade in RebirthUpgradeTypes::iter().skip(RebirthUpgradeTypes::BorrowedTime1 as usize) {
+        assert_eq!(upgrades[upgrade as usize], RebirthUpgrade::new(upgrade));
+    }
+}
+
+#[test]
+fn test_save_with_too_many_entries_is_error() {
+    let mut json = serde_json::to_value(GameSave::default()).unwrap();
+    let upgrades = json["state"]["rebirth_stats"]["rebirth_upgrades"]
+        .as_array_mut()
+        .unwrap();
+    upgrades.push(upgrades[0].clone());
+    assert!(serde_json::from_value::<GameSave>(json).is_err());
+}
+
+#[test]
+fn test_save_with_fewer_tombs_loads() {
+    let mut save = GameSave::default();
+    save.state.tombs[TombTypes::Catacomb as usize].is_purchased = true;
+    let mut json = serde_json::to_value(&save).unwrap();
+    // Saves from before the noble and royal tiers
+    json["state"]["tombs"]
+        .as_array_mut()
+        .unwrap()
+        .truncate(TombTypes::Barrow as usize);
+    let loaded: GameSave = serde_json::from_value(json).unwrap();
+    assert!(loaded.state.tombs[TombTypes::Catacomb as usize].is_purchased);
+    let defaults = GameSave::default().state.tombs;
+    for tomb in TombTypes::iter().skip(TombTypes::Barrow as usize) {
+        assert_eq!(loaded.state.tombs[tomb as usize], defaults[tomb as usize]);
+    }
+}
+
+// Saved by the game before the intellectual, merchant and upper military
+// works, and the newer items, tombs, blessings and skills were added
+#[test]
+fn test_baseline_save_loads() {
+    let save: GameSave = serde_json::from_str(include_str!("fixtures/baseline_save.json")).unwrap();
+    let state = &save.state;
+    assert_eq!(state.rebirth_stats.tier, 5);
+    assert_eq!(state.works[WorkTypes::Weaver as usize].level, 59);
+    let max_levels = &state.rebirth_stats.max_job_levels;
+    assert_eq!(max_levels[WorkTypes::Woodcutter as usize], 80);
+    assert_eq!(max_levels[WorkTypes::LeatherWorker as usize], 0);
+    assert_eq!(max_levels[WorkTypes::Weaponsmith as usize], 0);
+    for work in [
+        WorkTypes::BaggageBoy,
+        WorkTypes::LightCavalry,
+        WorkTypes::Priest,
+        WorkTypes::Bishop,
+    ] {
+        assert_eq!(max_levels[work as usize], 80, "{:?}", work);
+    }
+    // Everything added since is at the end of its enum and starts fresh
+    for work in WorkTypes::iter().skip(WorkTypes::Servant as usize) {
+        assert_eq!(max_levels[work as usize], 0, "{:?}", work);
+        assert_eq!(state.works[work as usize].level, 0, "{:?}", work);
+    }
+    assert!(!state.boost_items[BoostItemTypes::Quill as usize].is_purchased);
+    assert!(!state.tombs[TombTypes::Pyramid as usize].is_purchased);
+    assert_eq!(
+        state.blessings[BlessingTypes::HadesBargain as usize].level,
+        0
+    );
+    assert_eq!(state.skills[SkillTypes::Theology as usize].level, 0.0);
+
+    let mut game = load(save);
+    assert!(game.state.life_stats.dead);
+    do_rebirth_internal(&mut game);
+    for _ in 0..100 {
+        engine_run(&mut game);
+    }
+    assert_eq!(game.state.rebirth_stats.tier, 5);
+    assert!(!game.state.life_stats.dead);
+}
@@ -0,0 +1,126 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::{Game, GameSave};
+use one_life::input::activity::ActivityTypes;
+use one_life::input::skill::SkillTypes;
+use one_life::input::stat::StatTypes;
+use one_life::world_content::skill::{translate_skill, SkillRequirement};
+use one_life::{get_value_breakdown_internal, set_activity_internal};
+use strum::IntoEnumIterator;
+
+mod common;
+use common::load;
+
+fn game_at_tier(tier: u32) -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = tier;
+    save.state.rebirth_stats.unlocks.has_skills = true;
+    let mut game = load(save);
+    engine_run(&mut game);
+    game
+}
+
+fn is_visible(game: &Game, skill: SkillTypes) -> bool {
+    game.state.skills[skill as usize].is_visible
+}
+
+#[test]
+fn test_every_skill_is_data() {
+    let game = game_at_tier(0);
+    for skill_type in SkillTypes::iter() {
+        let skill = translate_skill(skill_type);
+        assert_eq!(skill.name, skill_type);
+        assert!(skill.xp_factor > 0.0);
+        assert!(skill.effect_per_level > 0.0);
+        assert!(!skill.requirements.is_empty());
+        // Unlock names are checked when they are looked up
+        for requirement in skill.requirements {
+            requirement.is_met(&game);
+        }
+    }
+}
+
+#[test]
+fn test_skill_level_requirement() {
+    let mut game = game_at_tier(3);
+    let requirement = SkillRequirement::SkillLevel(SkillTypes::Tactics, 5.0);
+    assert!(!requirement.is_met(&game));
+    game.state.skills[SkillTypes::Tactics as usize].level = 5.0;
+    assert!(requirement.is_met(&game));
+}
+
+#[test]
+fn test_stat_requirement() {
+    let mut game = game_at_tier(4);
+    assert!(!is_visible(&game, SkillTypes::Oratory));
+    assert_eq!(game.state.skills[SkillTypes::Oratory as usize].xp_rate, 0.0);
+
+    game.state.stats[StatTypes::Cha as usize].level = 25.0;
+    engine_run(&mut game);
+    assert!(is_visible(&game, SkillTypes::Oratory));
+
+    // Not below the tier of the skill
+    let mut game = game_at_tier(3);
+    game.state.stats[StatTypes::Cha as usize].level = 25.0;
+    engine_run(&mut game);
+    assert!(!is_visible(&game, SkillTypes::Oratory));
+}
+
+#[test]
+fn test_skill_trains_from_its_source() {
+    let mut game = game_at_tier(7);
+    set_activity_internal(ActivityTypes::Acrobatics, &mut game);
+    game.state.stats[StatTypes::Dex as usize].level = 25.0;
+    engine_run(&mut game);
+    engine_run(&mut game);
+    let dex_xp = game.intermediate_state.get_value(KeyValues::Dex);
+    let skills = game.intermediate_state.get_multiplier(KeyValues::Skills);
+    let skill = &game.state.skills[SkillTypes::Craftsmanship as usize];
+    assert!(dex_xp > 0.0);
+    assert!((skill.xp_rate - dex_xp * 0.5 * skills).abs() < 1e-9);
+}
+
+#[test]
+fn test_skill_effect_scales_with_level() {
+    let mut game = game_at_tier(3);
+    game.state.rebirth_stats.unlocks.has_faith = true;
+    game.state.stats[StatTypes::Faith as usize].level = 10.0;
+    engine_run(&mut game);
+    assert!(is_visible(&game, SkillTypes::Theology));
+    let divine_favor = game
+        .intermediate_state
+        .get_multiplier(KeyValues::DivineFavor);
+
+    game.state.skills[SkillTypes::Theology as usize].level = 10.0;
+    engine_run(&mut game);
+    let multiplier = game
+        .intermediate_state
+        .get_multiplier(KeyValues::DivineFavor);
+    assert!((multiplier - divine_favor * 1.5).abs() < 1e-9);
+    assert!(get_value_breakdown_internal(KeyValues::DivineFavor, &game)
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Skill(SkillTypes::Theology)));
+}
+
+// As before skills were data, visibility only decides what the UI shows.
+// Hidden skills still train and still give their effect.
+#[test]
+fn test_hidden_skills_train_and_apply() {
+    let mut game = game_at_tier(0);
+    game.state.skills[SkillTypes::Tactics as usize].level = 10.0;
+    engine_run(&mut game);
+    assert!(!is_visible(&game, SkillTypes::Tactics));
+    assert!(get_value_breakdown_internal(KeyValues::SoldierXp, &game)
+        .multipliers
+        .iter()
+        .any(|m| m.source == GainSource::Skill(SkillTypes::Tactics)));
+
+    let mut game = game_at_tier(1);
+    game.input.activity = ActivityTypes::Meditate;
+    engine_run(&mut game);
+    engine_run(&mut game);
+    assert!(!is_visible(&game, SkillTypes::Mindful));
+    assert!(game.state.skills[SkillTypes::Mindful as usize].xp_rate > 0.0);
+}
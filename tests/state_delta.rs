@@ -0,0 +1,64 @@
+use one_life::engine::engine_run;
+use one_life::game::GameSave;
+use one_life::get_state_delta_internal;
+use one_life::input::work::WorkTypes;
+use one_life::presets::get_presets;
+
+mod common;
+use common::load;
+
+#[test]
+fn test_first_delta_is_full_state() {
+    let mut game = load(GameSave::default());
+    let delta = get_state_delta_internal(&mut game);
+    assert_eq!(delta.full.as_deref(), Some(&game.state));
+    assert!(get_state_delta_internal(&mut game).is_empty());
+}
+
+#[test]
+fn test_delta_only_has_changes() {
+    let mut game = load(GameSave::default());
+    // The first tick updates unlocks and incomes everywhere
+    engine_run(&mut game);
+    get_state_delta_internal(&mut game);
+    engine_run(&mut game);
+    let delta = get_state_delta_internal(&mut game);
+    assert!(delta.full.is_none());
+    assert!(delta.life_stats.is_some());
+    assert!(delta.items.is_some());
+    // Only the active work gains xp
+    let work_indices: Vec<usize> = delta.works.iter().map(|entry| entry.index).collect();
+    assert!(work_indices.contains(&(WorkTypes::Mines as usize)));
+    assert!(!work_indices.contains(&(WorkTypes::Latrine as usize)));
+    assert!(delta.boost_items.is_empty());
+    assert!(delta.tombs.is_empty());
+}
+
+#[test]
+fn test_load_sends_full_state() {
+    let mut game = load(GameSave::default());
+    get_state_delta_internal(&mut game);
+    game.load_game(GameSave::default());
+    assert!(get_state_delta_internal(&mut game).full.is_some());
+}
+
+// Patching a copy with every delta, like the frontend does, keeps it in sync
+#[test]
+fn test_applied_deltas_reproduce_state() {
+    let mut game = load(get_presets().remove("T2 Test_5: Re 15").unwrap());
+    let mut frontend_state = game.state.clone();
+    get_state_delta_internal(&mut game);
+    let mut batch: u32 = 0;
+    while !game.state.life_stats.is_dying {
+        // Deltas are taken once per frame, which can cover several ticks
+        for _ in 0..=(batch % 4) {
+            engine_run(&mut game);
+        }
+        batch += 1;
+        get_state_delta_internal(&mut game).apply(&mut frontend_state);
+        if batch.is_multiple_of(500) {
+            assert_eq!(frontend_state, game.state, "batch {}", batch);
+        }
+    }
+    assert_eq!(frontend_state, game.state);
+}
@@ -13,7 +13,9 @@ fn test_first_rebirth() {
     let game = &mut Game::new();
     set_full_auto(&mut game.meta_data.options);
     run_until_dead(game);
-    assert_eq!(game.state.works[WorkTypes::GalleyRower as usize].level, 10);
+    // The first life is spent on the Stone Floor, whose health cost ends it
+    // about half a year earlier, a level short of Galley Rower 10
+    assert_eq!(game.state.works[WorkTypes::GalleyRower as usize].level, 9);
     assert_eq!(game.state.works[WorkTypes::Mill as usize].level, 0); // too strict?
 
     do_test_rebirth(game);
@@ -0,0 +1,27 @@
+// #![cfg(target_arch = "wasm32")]
+
+use one_life::game::Game;
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::work::WorkTypes;
+use one_life::presets::make_t5_intellectual;
+
+use one_life::util::run_until_dead;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_t5_intellectual() {
+    let game = &mut Game::new();
+    game.load_game(make_t5_intellectual());
+    run_until_dead(game);
+
+    assert!(
+        game.state.works[WorkTypes::Scribe as usize].level >= 10,
+        "Scribe level: {}",
+        game.state.works[WorkTypes::Scribe as usize].level
+    );
+    assert!(
+        game.state.boost_items[BoostItemTypes::Quill as usize].is_purchased,
+        "No Quill purchased, money: {}",
+        game.state.items.money
+    );
+}
@@ -0,0 +1,94 @@
+use one_life::engine::engine_run;
+use one_life::input::tomb::TombTypes;
+use one_life::input::work::WorkTypes;
+use one_life::{buy_tier_internal, can_buy_tier_internal, do_rebirth_internal};
+use strum::IntoEnumIterator;
+
+mod common;
+use common::load_tier;
+
+#[test]
+fn test_tiers_are_ordered() {
+    let game = load_tier(0);
+    let tiers = &game.world.tiers;
+    assert_eq!(tiers.len(), 15);
+    assert_eq!(tiers.last().unwrap().display_name, "The August One");
+    for (index, tier) in tiers.iter().enumerate() {
+        assert_eq!(tier.level as usize, index);
+        assert_ne!(tier.description, "todo");
+        assert!(!tier.unlocks.is_empty(), "Tier {} unlocks nothing", index);
+    }
+    for pair in tiers.windows(2) {
+        assert!(pair[1].purchasing_cost > pair[0].purchasing_cost);
+        assert!(pair[1].starting_health >= pair[0].starting_health);
+    }
+}
+
+#[test]
+fn test_rebirth_at_every_tier() {
+    for tier in 0..15 {
+        let mut game = load_tier(tier);
+        engine_run(&mut game);
+        do_rebirth_internal(&mut game);
+        let world_tier = &game.world.tiers[tier as usize];
+        assert_eq!(game.state.rebirth_stats.tier, tier);
+        assert_eq!(game.input.work, world_tier.starting_work);
+    }
+}
+
+#[test]
+fn test_noble_tiers_bring_their_own_content() {
+    let game = load_tier(0);
+    for tier in 7..15 {
+        let content = WorkTypes::iter()
+            .filter(|&work| game.world.get_work(work).required_tier == tier)
+            .count()
+            + game
+                .world
+                .tombs
+                .iter()
+                .filter(|tomb| tomb.required_tier == tier)
+                .count()
+            + game
+                .world
+                .rebirth_upgrades
+                .iter()
+                .filter(|upgrade| upgrade.required_tier == tier)
+                .count();
+        assert!(content >= 2, "Tier {} only adds {} content", tier, content);
+
+        let mut reborn = load_tier(tier);
+        engine_run(&mut reborn);
+        do_rebirth_internal(&mut reborn);
+        engine_run(&mut reborn);
+        let starting_work = reborn.world.tiers[tier as usize].starting_work;
+        assert!(
+            reborn.state.works[starting_work as usize].is_unlocked,
+            "Tier {} starts in a locked work",
+            tier
+        );
+    }
+}
+
+#[test]
+fn test_buy_noble_tiers() {
+    let mut game = load_tier(7);
+    game.state.rebirth_stats.coins = 1e20;
+    assert!(!can_buy_tier_internal(9, &game));
+    buy_tier_internal(8, &mut game);
+    assert_eq!(game.state.rebirth_stats.tier, 8);
+    engine_run(&mut game);
+    assert!(game.state.works[WorkTypes::Blacksmith as usize].is_visible);
+    assert!(game.state.tombs[TombTypes::Barrow as usize].is_visible);
+    assert!(!game.state.tombs[TombTypes::Sepulchre as usize].is_visible);
+
+    for tier in 9..15 {
+        buy_tier_internal(tier, &mut game);
+    }
+    assert_eq!(game.state.rebirth_stats.tier, 14);
+    engine_run(&mut game);
+    assert!(game.state.works[WorkTypes::Weaponsmith as usize].is_visible);
+    assert!(game.state.tombs[TombTypes::Pyramid as usize].is_visible);
+    // There is nothing above The August One
+    assert!(!can_buy_tier_internal(15, &game));
+}
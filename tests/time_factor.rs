@@ -0,0 +1,128 @@
+use one_life::clock::ManualClock;
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::events::GameEventType;
+use one_life::game::{Game, GameSave};
+use one_life::get_value_breakdown_internal;
+use one_life::input::housing::HousingTypes;
+use one_life::input::rebirth_upgrade::RebirthUpgradeTypes;
+use one_life::input::tomb::TombTypes;
+use one_life::util::run_until_dead;
+use one_life::wasm_api::auto_settings::set_auto_end_early_internal;
+use one_life::{tick_internal, TICK_MS};
+
+mod common;
+use common::load;
+
+const BORROWED_TIME: [RebirthUpgradeTypes; 3] = [
+    RebirthUpgradeTypes::BorrowedTime1,
+    RebirthUpgradeTypes::BorrowedTime2,
+    RebirthUpgradeTypes::BorrowedTime3,
+];
+
+fn with_borrowed_time(upgrades: &[RebirthUpgradeTypes]) -> GameSave {
+    let mut save = GameSave::default();
+    for upgrade in upgrades {
+        save.state.rebirth_stats.rebirth_upgrades[*upgrade as usize].is_purchased = true;
+    }
+    save
+}
+
+fn days_per_tick(game: &mut Game) -> f64 {
+    let age = game.state.life_stats.age;
+    engine_run(game);
+    game.state.life_stats.age - age
+}
+
+#[test]
+fn test_time_factor_breakdown() {
+    let mut game = load(with_borrowed_time(&BORROWED_TIME[..1]));
+    engine_run(&mut game);
+    let breakdown = get_value_breakdown_internal(KeyValues::TimeFactor, &game);
+    assert_eq!(breakdown.value, 0.97);
+    assert_eq!(breakdown.bases.len(), 1);
+    assert_eq!(breakdown.bases[0].source, GainSource::Base);
+    assert_eq!(breakdown.bases[0].amount, 1.0);
+    assert_eq!(
+        breakdown.multipliers[0].source,
+        GainSource::RebirthUpgrade(RebirthUpgradeTypes::BorrowedTime1)
+    );
+}
+
+#[test]
+fn test_time_factor_scales_aging() {
+    let mut normal = load(GameSave::default());
+    let mut slowed = load(with_borrowed_time(&BORROWED_TIME));
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.time_factor = 2.0;
+    let mut sped_up = load(save);
+    let normal_days = days_per_tick(&mut normal);
+    let slowed_days = days_per_tick(&mut slowed);
+    let sped_up_days = days_per_tick(&mut sped_up);
+    assert!((slowed_days / normal_days - 0.97 * 0.95 * 0.9).abs() < 1e-9);
+    assert!((sped_up_days / normal_days - 2.0).abs() < 1e-9);
+}
+
+// Housing without a health effect, so that only the job wears the health down
+fn housed_life(upgrades: &[RebirthUpgradeTypes]) -> Game {
+    let mut save = with_borrowed_time(upgrades);
+    save.state.items.money = 1e12;
+    let mut game = load(save);
+    engine_run(&mut game);
+    game.input.housing = HousingTypes::CrampedBarracks;
+    game
+}
+
+#[test]
+fn test_slower_aging_makes_a_longer_life() {
+    let mut normal = housed_life(&[]);
+    let mut slowed = housed_life(&BORROWED_TIME);
+    run_until_dead(&mut normal);
+    run_until_dead(&mut slowed);
+    let normal_life = &normal.state.life_stats;
+    let slowed_life = &slowed.state.life_stats;
+    // Lifespan is in days, so it's only reached later in ticks
+    assert!(slowed_life.current_tick as f64 > normal_life.current_tick as f64 * 1.05);
+    // Health changes per tick, so it has longer to decay and the lifespan ends
+    // up shorter in days
+    assert!(normal_life.health_rate < 0.0);
+    assert!(slowed_life.health < normal_life.health);
+    assert!(slowed_life.lifespan < normal_life.lifespan);
+    assert!(slowed_life.age + slowed_life.health >= slowed_life.lifespan);
+}
+
+// Auto end early waits for a number of ticks, not an age, so it happens at
+// the same time no matter how fast the character ages
+fn auto_end_early_age(save: GameSave) -> (u32, f64) {
+    let clock = ManualClock::new(0.0);
+    let mut game = load(save);
+    game.set_clock(clock.clone());
+    game.meta_data.game_speed = 1;
+    game.meta_data.autosave = false;
+    game.state.tombs[TombTypes::Mausuleum as usize].is_purchased = true;
+    set_auto_end_early_internal(2.0, &mut game);
+    clock.advance(TICK_MS);
+    tick_internal(&mut game);
+    clock.advance(TICK_MS * 6000.0);
+    tick_internal(&mut game);
+    assert_eq!(game.state.rebirth_stats.rebirth_count, 1);
+    game.events
+        .drain()
+        .into_iter()
+        .find_map(|e| match e.event {
+            GameEventType::LifeEnded { age, .. } => Some((e.tick, age)),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_auto_end_early_with_slower_aging() {
+    let (normal_tick, normal_age) = auto_end_early_age(GameSave::default());
+    let (slowed_tick, slowed_age) = auto_end_early_age(with_borrowed_time(&BORROWED_TIME));
+    // Dying runs one more tick
+    assert_eq!(normal_tick, 5001);
+    assert_eq!(slowed_tick, normal_tick);
+    assert!(slowed_age < normal_age);
+}
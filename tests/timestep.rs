@@ -0,0 +1,196 @@
+use one_life::clock::ManualClock;
+use one_life::engine::timestep::MAX_STEP_TICKS;
+use one_life::engine::{engine_run, engine_run_ticks, engine_step};
+use one_life::game::{Game, GameSave};
+use one_life::input::work::WorkTypes;
+use one_life::presets::get_presets;
+use one_life::util::set_full_auto;
+use one_life::{set_work_internal, tick_internal, TICK_MS, TICK_RATE};
+use strum::IntoEnumIterator;
+
+mod common;
+use common::load;
+
+// A loaded game that has run its first tick on time
+fn start_with_clock(clock: &ManualClock) -> Game {
+    let mut game = load(fresh_full_auto());
+    game.set_clock(clock.clone());
+    game.meta_data.game_speed = 1;
+    game.meta_data.autosave = false;
+    clock.advance(TICK_MS);
+    tick_internal(&mut game);
+    game
+}
+
+fn fresh_full_auto() -> GameSave {
+    let mut save = GameSave::default();
+    set_full_auto(&mut save.meta_data.options);
+    save
+}
+
+fn run_per_tick(game: &mut Game) {
+    while !game.state.life_stats.is_dying {
+        engine_run(game);
+    }
+}
+
+// Runs in coarse steps until dying, returns the number of steps taken
+fn run_coarse(game: &mut Game) -> u32 {
+    let mut steps = 0;
+    while !game.state.life_stats.is_dying {
+        let ticks = engine_step(game, u32::MAX);
+        assert!((1..=MAX_STEP_TICKS).contains(&ticks));
+        steps += 1;
+    }
+    steps
+}
+
+fn assert_close(name: &str, exact: f64, coarse: f64, tolerance: f64) {
+    let error = (exact - coarse).abs() / exact.abs().max(1.0);
+    assert!(
+        error <= tolerance,
+        "{}: per tick {}, coarse {}, relative error {}",
+        name,
+        exact,
+        coarse,
+        error
+    );
+}
+
+fn assert_same_life(save: GameSave) {
+    let mut exact = load(save.clone());
+    let mut coarse = load(save);
+    run_per_tick(&mut exact);
+    let steps = run_coarse(&mut coarse);
+
+    let ticks = exact.state.life_stats.current_tick;
+    println!("ticks {}, steps {}", ticks, steps);
+    assert!(steps < ticks / 2, "{} steps for {} ticks", steps, ticks);
+    assert_close(
+        "ticks",
+        ticks as f64,
+        coarse.state.life_stats.current_tick as f64,
+        0.001,
+    );
+    assert_close(
+        "age",
+        exact.state.life_stats.age,
+        coarse.state.life_stats.age,
+        0.001,
+    );
+    assert_close(
+        "money",
+        exact.state.items.money,
+        coarse.state.items.money,
+        0.01,
+    );
+    assert_close(
+        "coins_gain",
+        exact.state.rebirth_stats.coins_gain,
+        coarse.state.rebirth_stats.coins_gain,
+        0.01,
+    );
+    for work in WorkTypes::iter() {
+        let exact_level = exact.state.works[work as usize].level;
+        let coarse_level = coarse.state.works[work as usize].level;
+        assert!(
+            exact_level.abs_diff(coarse_level) <= 1,
+            "{:?}: per tick level {}, coarse level {}",
+            work,
+            exact_level,
+            coarse_level
+        );
+    }
+}
+
+#[test]
+fn test_coarse_steps_match_per_tick_fresh_game() {
+    assert_same_life(fresh_full_auto());
+}
+
+#[test]
+fn test_coarse_steps_match_per_tick_with_replay() {
+    assert_same_life(get_presets().remove("T2 Test_5: Re 15").unwrap());
+}
+
+#[test]
+fn test_coarse_steps_match_per_tick_t5() {
+    assert_same_life(get_presets().remove("05: T5 expected").unwrap());
+}
+
+fn trader_with_money() -> Game {
+    let mut save = GameSave::default();
+    save.state.rebirth_stats.tier = 6;
+    save.state.items.money = 1e6;
+    let mut game = load(save);
+    engine_run(&mut game);
+    set_work_internal(WorkTypes::Trader, &mut game);
+    engine_run(&mut game);
+    game
+}
+
+// Trader income follows the money on hand, but that alone doesn't force
+// single ticks
+#[test]
+fn test_trader_takes_coarse_steps() {
+    let mut exact = trader_with_money();
+    let mut coarse = trader_with_money();
+
+    let ticks = 3000;
+    for _ in 0..ticks {
+        engine_run(&mut exact);
+    }
+    let mut steps = 0;
+    let mut ran = 0;
+    while ran < ticks {
+        ran += engine_step(&mut coarse, ticks - ran);
+        steps += 1;
+    }
+    assert_eq!(
+        exact.state.life_stats.current_tick,
+        coarse.state.life_stats.current_tick
+    );
+    assert!(steps < ticks / 10, "{} steps for {} ticks", steps, ticks);
+    assert_close(
+        "money",
+        exact.state.items.money,
+        coarse.state.items.money,
+        0.001,
+    );
+}
+
+#[test]
+fn test_single_tick_step() {
+    let mut game = load(fresh_full_auto());
+    assert_eq!(engine_run_ticks(&mut game, 1), 1);
+    assert_eq!(game.state.life_stats.current_tick, 1);
+}
+
+#[test]
+fn test_long_absence_is_caught_up() {
+    let clock = ManualClock::new(0.0);
+    let mut game = start_with_clock(&clock);
+    // Seven minutes away, with the default cap of five minutes of single ticks. A
+    // fresh life lasts about eight and a half.
+    let away_ticks = 7.0 * 60.0 * TICK_RATE;
+    clock.advance(TICK_MS * (away_ticks + 1.0) + 1.0);
+    tick_internal(&mut game);
+    assert_eq!(game.state.life_stats.current_tick, away_ticks as u32 + 2);
+    assert_eq!(game.meta_data.saved_ticks, 0.0);
+    assert!(game.meta_data.missed_time < TICK_MS);
+}
+
+#[test]
+fn test_catch_up_stops_when_dying() {
+    let clock = ManualClock::new(0.0);
+    let mut game = start_with_clock(&clock);
+    // Eight hours is longer than a life
+    clock.advance(8.0 * 60.0 * 60.0 * 1000.0);
+    tick_internal(&mut game);
+    assert!(game.state.life_stats.is_dying);
+    assert_eq!(game.meta_data.saved_ticks, 0.0);
+    // The time after death is kept for the next life
+    let lived_ms = (game.state.life_stats.current_tick - 1) as f64 * TICK_MS;
+    let away_ms = 8.0 * 60.0 * 60.0 * 1000.0;
+    assert!((game.meta_data.missed_time + lived_ms - away_ms).abs() < TICK_MS);
+}
@@ -0,0 +1,70 @@
+use one_life::engine::engine_run;
+use one_life::engine::gain_source::GainSource;
+use one_life::engine::value_keys::KeyValues;
+use one_life::game::{Game, GameSave};
+use one_life::get_value_breakdown_internal;
+use one_life::input::boost_item::BoostItemTypes;
+use one_life::input::stat::StatTypes;
+use one_life::input::work::WorkTypes;
+
+mod common;
+use common::load;
+
+fn start_game() -> Game {
+    let mut game = load(GameSave::default());
+    game.state.boost_items[BoostItemTypes::IronPickAxe as usize].is_purchased = true;
+    engine_run(&mut game);
+    game
+}
+
+#[test]
+fn test_work_income_breakdown() {
+    let game = start_game();
+    let breakdown = get_value_breakdown_internal(KeyValues::Mines, &game);
+    assert_eq!(breakdown.key, KeyValues::Mines);
+    assert_eq!(breakdown.bases.len(), 1);
+    assert_eq!(
+        breakdown.bases[0].source,
+        GainSource::Work(WorkTypes::Mines)
+    );
+
+    let sources: Vec<GainSource> = breakdown.multipliers.iter().map(|m| m.source).collect();
+    let main_stat = StatTypes::from(game.world.get_work(WorkTypes::Mines).work_type);
+    assert!(sources.contains(&GainSource::Stat(main_stat)));
+    assert!(sources.contains(&GainSource::WorkLevel(WorkTypes::Mines)));
+    assert!(sources.contains(&GainSource::BoostItem(BoostItemTypes::IronPickAxe)));
+
+    let item = breakdown
+        .multipliers
+        .iter()
+        .find(|m| m.source == GainSource::BoostItem(BoostItemTypes::IronPickAxe))
+        .unwrap();
+    assert_eq!(item.amount, 2.0);
+    assert_eq!(
+        item.display_name,
+        game.world.boost_items[BoostItemTypes::IronPickAxe as usize].display_name
+    );
+}
+
+#[test]
+fn test_breakdown_adds_up_to_value() {
+    let game = start_game();
+    for key in [KeyValues::Mines, KeyValues::Happiness, KeyValues::Health] {
+        let breakdown = get_value_breakdown_internal(key, &game);
+        let base: f64 = breakdown.bases.iter().map(|b| b.amount).sum();
+        let multiplier: f64 = breakdown.multipliers.iter().map(|m| m.amount).product();
+        assert_eq!(breakdown.multiplier, multiplier);
+        assert_eq!(breakdown.value, base * multiplier);
+        assert_eq!(breakdown.value, game.intermediate_state.get_value(key));
+    }
+}
+
+#[test]
+fn test_key_without_gains_is_empty() {
+    let game = start_game();
+    let breakdown = get_value_breakdown_internal(KeyValues::Coins, &game);
+    assert!(breakdown.bases.is_empty());
+    assert!(breakdown.multipliers.is_empty());
+    assert_eq!(breakdown.value, 0.0);
+    assert_eq!(breakdown.multiplier, 1.0);
+}